@@ -0,0 +1,186 @@
+//! Compares the rows of `SemicircleFilled` with the previous pixel by pixel stepping
+//! and measures both, then measures the time per row for growing radii.
+//!
+//! The end of each row is computed directly instead of stepping along its edge, so the rows
+//! near the top, whose edges are longest, must be faster than with the previous stepping.
+//!
+//! Run with `cargo bench --bench semicircle`, an optional argument sets the end of
+//! the radius range, which defaults to 100,000.
+use rasterization::SemicircleFilled;
+use std::hint::black_box;
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+/// The previous implementation, which steps through every pixel of the edge in a single loop.
+#[derive(Clone)]
+struct Reference {
+    x: i64,
+    y: i64,
+    err: i64,
+}
+
+impl Reference {
+    fn new(radius: i64) -> Self {
+        Self {
+            x: -radius,
+            y: 0,
+            err: 2 - 2 * radius,
+        }
+    }
+}
+
+impl Iterator for Reference {
+    type Item = (Range<i64>, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.x == 0 {
+            return None;
+        }
+
+        let xy = (self.x..-self.x, -(self.y + 1));
+
+        loop {
+            let y_tmp = self.y;
+            let err_tmp = self.err;
+
+            if err_tmp <= self.y {
+                self.y += 1;
+                self.err += 2 * self.y + 2;
+            };
+
+            if err_tmp > self.x || self.err > self.y {
+                self.x += 1;
+                self.err += 2 * self.x + 2;
+            }
+
+            if y_tmp != self.y || self.x == 0 {
+                break;
+            }
+        }
+
+        Some(xy)
+    }
+}
+
+impl DoubleEndedIterator for Reference {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.x == 0 {
+            return None;
+        }
+
+        loop {
+            let x_tmp = self.x;
+            let y_tmp = self.y;
+            let err_tmp = self.err;
+
+            if err_tmp <= self.y {
+                self.y += 1;
+                self.err += 2 * self.y + 2;
+            };
+
+            if err_tmp > self.x || self.err > self.y {
+                self.x += 1;
+                self.err += 2 * self.x + 2;
+                if self.y == y_tmp {
+                    return Some((-(self.y + 1)..(self.y + 1), (self.x - 1)));
+                }
+            }
+
+            if x_tmp != self.x {
+                let xy = (-self.y..self.y, (self.x - 1));
+
+                return Some(xy);
+            }
+        }
+    }
+}
+
+fn time<I: Iterator<Item = (Range<i64>, i64)>>(
+    radii: Range<i64>,
+    iter: impl Fn(i64) -> I,
+) -> Duration {
+    let start = Instant::now();
+    for radius in radii {
+        iter(radius).for_each(|row| {
+            black_box(row);
+        });
+    }
+    start.elapsed()
+}
+
+/// Returns the average time per row over all rows of the radius.
+fn time_per_row<I: Iterator<Item = (Range<i64>, i64)>>(radius: i64, iter: I) -> Duration {
+    let start = Instant::now();
+    iter.for_each(|row| {
+        black_box(row);
+    });
+    start.elapsed() / radius as u32
+}
+
+/// Returns the time of the rows left after skipping `skip` rows.
+fn time_rows<I: Iterator<Item = (Range<i64>, i64)>>(mut iter: I, skip: usize) -> Duration {
+    if skip > 0 {
+        iter.nth(skip - 1);
+    }
+    let start = Instant::now();
+    iter.for_each(|row| {
+        black_box(row);
+    });
+    start.elapsed()
+}
+
+fn main() {
+    let end = std::env::args()
+        .skip(1)
+        .find(|arg| !arg.starts_with('-'))
+        .map_or(100_000, |arg| arg.parse().expect("radius range end"));
+
+    for radius in 0..end {
+        assert!(
+            SemicircleFilled::<i64>::new(radius as u64).eq(Reference::new(radius)),
+            "rows differ for radius {radius}"
+        );
+        assert!(
+            SemicircleFilled::<i64>::new(radius as u64)
+                .rev()
+                .eq(Reference::new(radius).rev()),
+            "reversed rows differ for radius {radius}"
+        );
+    }
+    println!("rows are equal for radii 0..{end}");
+
+    let reference = time(0..end, Reference::new);
+    let current = time(0..end, |radius| SemicircleFilled::<i64>::new(radius as u64));
+    println!("next:      reference {reference:?}, current {current:?}");
+
+    let reference = time(0..end, |radius| Reference::new(radius).rev());
+    let current = time(0..end, |radius| {
+        SemicircleFilled::<i64>::new(radius as u64).rev()
+    });
+    println!("next_back: reference {reference:?}, current {current:?}");
+
+    for exponent in 3..=8 {
+        let radius = 10_i64.pow(exponent);
+        let iter = SemicircleFilled::<i64>::new(radius as u64);
+        let next = time_per_row(radius, iter.clone());
+        let next_back = time_per_row(radius, iter.rev());
+        println!("radius {radius:>11}: next {next:?} per row, next_back {next_back:?} per row");
+    }
+
+    // The rows next to the top of large semicircles have edges of hundreds of pixels.
+    const ROWS: i64 = 100;
+    for exponent in 7..=8 {
+        let radius = 10_i64.pow(exponent);
+        let skip = (radius - ROWS) as usize;
+        let iter = SemicircleFilled::<i64>::new(radius as u64);
+        let reference = time_rows(Reference::new(radius), skip);
+        let current = time_rows(iter.clone(), skip);
+        println!("radius {radius:>11}: top {ROWS} rows by next: reference {reference:?}, current {current:?}");
+        assert!(current < reference, "next is not faster for radius {radius}");
+
+        let reference = time_rows(Reference::new(radius).rev().take(ROWS as usize), 0);
+        let current = time_rows(iter.rev().take(ROWS as usize), 0);
+        println!("radius {radius:>11}: top {ROWS} rows by next_back: reference {reference:?}, current {current:?}");
+        assert!(current < reference, "next_back is not faster for radius {radius}");
+    }
+}
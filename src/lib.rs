@@ -14,10 +14,13 @@
 //!     .rustdoc-hidden { display: none; }
 //! </style>
 use colorous::Gradient;
+use core::cmp::Ordering;
 use core::fmt::{Debug, Display};
-use core::iter::FusedIterator;
-use core::ops::{Add, AddAssign, Mul, Neg, Range, Sub};
-use num::{One, Unsigned, Zero};
+use core::iter::{once, FusedIterator};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Neg, Range, RangeInclusive, Sub};
+use num::integer::Roots;
+use num::{One, PrimInt, Signed, ToPrimitive, Unsigned, Zero};
 use num_convert::FromAs;
 
 /// Enum for selecting the gradient direction and setting the color gradient type.
@@ -41,16 +44,214 @@ pub enum DirectionGradient {
     BottomRight(Gradient),
 }
 
+/// Enum for selecting where a stroke of an [`Annulus`] lies relative to the nominal radius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrokeAlignment {
+    /// The stroke lies inside the disc of the radius.
+    Inside,
+    /// The stroke is centered on the edge of the disc, an odd extra pixel lies inside.
+    Centered,
+    /// The stroke lies outside the disc of the radius.
+    Outside,
+}
+
+/// Enum for selecting the shape of the ends of a [`ThickLine`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    /// The line ends at its end points.
+    Butt,
+    /// The line is extended by half of its width beyond its end points.
+    Square,
+    /// The line ends with the discs of half of its width around its end points.
+    Round,
+}
+
+/// Enum for selecting which points of a [`PolygonFilled`] lie inside it.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if a ray from it crosses the outline an odd number of times.
+    EvenOdd,
+    /// A point is inside if the outline winds around it a non-zero number of times.
+    #[default]
+    NonZero,
+}
+
+/// An error which can be returned when creating an iterator from a radius.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RadiusError<T> {
+    /// The radius cannot be converted to the coordinate type.
+    Conversion,
+    /// The radius exceeds the largest radius supported by the coordinate type.
+    TooLarge {
+        /// The largest supported radius.
+        max: T,
+    },
+}
+
+impl<T: Display> Display for RadiusError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Conversion => {
+                f.write_str("Not possible to convert radius to the coordinate type")
+            }
+            Self::TooLarge { max } => write!(f, "Radius is too large, the maximum is {}", max),
+        }
+    }
+}
+
 /// An iterator of successive coordinates of a filled semicircle, using Bresenham's algorithm.
 ///
 /// The semicircles are exactly equal in diameter to the circle.
 #[derive(Default, Debug, Clone, PartialEq)]
 pub struct SemicircleFilled<T> {
+    front: Cursor<T>,
+    back: Cursor<T>,
+    rows: T,
+}
+
+/// The number of steps along a row or a column before the end of the run is solved for.
+///
+/// Solving the quadratic for the end of the run costs about as much as this many steps.
+const SHORT_RUN: usize = 64;
+
+/// A point of the Bresenham path of a circle with its error term.
+///
+/// The path starts at `(-r, 0)` and moves right or down until it reaches the top of the circle.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Cursor<T> {
     x: T,
     y: T,
     err: T,
 }
 
+impl<T> Cursor<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Moves to the first point of the next row, or to the column `end` on the last row.
+    ///
+    /// The error term grows by `2 * x + step` for each step to the right and by `2 * y + step`
+    /// for each step down.
+    #[inline]
+    fn next_row(&mut self, step: T, end: T) {
+        let mut steps = 0;
+
+        while self.err > self.y {
+            self.right(step);
+
+            if self.x == end {
+                return;
+            }
+
+            // Long rows are solved instead of stepped.
+            steps += 1;
+            if steps == SHORT_RUN && self.err > self.y {
+                if let Some((x, err)) = self.row_end(step, end) {
+                    self.x = x;
+                    self.err = err;
+
+                    if self.x == end {
+                        return;
+                    }
+                }
+            }
+        }
+
+        let err = self.err;
+        self.down(step);
+
+        if err > self.x || self.err > self.y {
+            self.right(step);
+        }
+    }
+
+    /// Moves to the first point of the next column and returns the half width of its row.
+    #[inline]
+    fn next_column(&mut self) -> T {
+        let two = T::one() + T::one();
+        let mut steps = 0;
+
+        loop {
+            let (y, err) = (self.y, self.err);
+
+            if err <= self.y {
+                self.down(two);
+            }
+
+            if err > self.x || self.err > self.y {
+                self.right(two);
+                return if self.y == y { y + T::one() } else { self.y };
+            }
+
+            // Long columns are solved instead of stepped.
+            steps += 1;
+            if steps == SHORT_RUN {
+                if let Some((y, err)) = self.column_end() {
+                    self.y = y;
+                    self.err = err;
+                    self.right(two);
+                    return y;
+                }
+            }
+        }
+    }
+
+    /// Steps to the right.
+    #[inline]
+    fn right(&mut self, step: T) {
+        self.x = self.x + T::one();
+        self.err = self.err + (T::one() + T::one()) * self.x + step;
+    }
+
+    /// Steps down.
+    #[inline]
+    fn down(&mut self, step: T) {
+        self.y = self.y + T::one();
+        self.err = self.err + (T::one() + T::one()) * self.y + step;
+    }
+
+    /// Returns the column and the error term where the steps along the row end.
+    ///
+    /// After `d` steps the error term is `err + d² + (2x + 1 + step)·d`, so the end of the row
+    /// is the first root of a quadratic. Returns `None` if the terms overflow `i128`.
+    #[cold]
+    fn row_end(&self, step: T, end: T) -> Option<(T, T)> {
+        let (x, y, err) = (self.x.to_i128()?, self.y.to_i128()?, self.err.to_i128()?);
+        let b = (2 * x).checked_add(1 + step.to_i128()?)?;
+        let d = first_within(b, err - y)?.min(end.to_i128()? - x);
+        let err = err.checked_add(d.checked_mul(d + b)?)?;
+
+        Some((
+            <T as num::NumCast>::from(x + d)?,
+            <T as num::NumCast>::from(err)?,
+        ))
+    }
+
+    /// Returns the row and the error term where the steps along the column end.
+    ///
+    /// The steps down end as soon as the error term exceeds the row, or one step after it
+    /// exceeds the column. Returns `None` if the terms overflow `i128`.
+    #[cold]
+    fn column_end(&self) -> Option<(T, T)> {
+        let (x, y, err) = (self.x.to_i128()?, self.y.to_i128()?, self.err.to_i128()?);
+        let b = (2 * y).checked_add(2)?;
+        let error = |d: i128| err.checked_add(d.checked_mul(d.checked_add(b + 1)?)?);
+        let columns = first_above(b + 1, err.checked_sub(x)?)? + 1;
+        // The error term exceeds the column first unless it exceeds the row before that step.
+        let d = if error(columns - 1)? > y + columns - 1 {
+            first_above(b, err - y)?
+        } else {
+            columns
+        };
+        let err = error(d)?;
+
+        Some((
+            <T as num::NumCast>::from(y + d)?,
+            <T as num::NumCast>::from(err)?,
+        ))
+    }
+}
+
 impl<T> SemicircleFilled<T> {
     /// Creates a new `SemicircleFilled` iterator that generates pixel coordinates.
     ///
@@ -58,10 +259,12 @@ impl<T> SemicircleFilled<T> {
     ///
     /// This function will panic if the radius cannot be converted to type `T` or
     ///
-    /// if the radius exceeds the practical limit of 100,000,000.
+    /// if the radius exceeds [`SemicircleFilled::MAX_RADIUS`] of type `T`.
     ///
     /// This limit exists to prevent overflow in the calculation of the error term.
     ///
+    /// See [`SemicircleFilled::try_new`] for a constructor that returns an error instead.
+    ///
     /// # Arguments
     ///
     /// * `radius` - A non-negative integer representing the radius of the semicircle.
@@ -71,157 +274,4046 @@ impl<T> SemicircleFilled<T> {
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::SemicircleFilled;
+    /// use rasterization::SemicircleFilled;
+    ///
+    /// let radius = 5_u32;
+    /// let semicircle_iter = SemicircleFilled::<i32>::new(radius);
+    /// let vec = semicircle_iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-5..5, -1), (-5..5, -2), (-4..4, -3), (-3..3, -4), (-2..2, -5)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(radius: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+    {
+        match Self::try_new(radius) {
+            Ok(semicircle) => semicircle,
+            Err(RadiusError::Conversion) => panic!(
+                "{}",
+                format_args!("Not possible to convert radius to {}", radius)
+            ),
+            Err(RadiusError::TooLarge { .. }) => panic!("Radius is too large"),
+        }
+    }
+
+    /// Creates a new `SemicircleFilled` iterator, or returns an error if the radius is not supported.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RadiusError::Conversion`] if the radius cannot be converted to type `T` and
+    /// [`RadiusError::TooLarge`] with the largest supported radius if the radius exceeds it.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - A non-negative integer representing the radius of the semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{RadiusError, SemicircleFilled};
+    ///
+    /// let vec = SemicircleFilled::<i16>::try_new(2_u32).unwrap().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..2, -1), (-1..1, -2)]);
+    /// assert_eq!(SemicircleFilled::<i8>::try_new(300_u32), Err(RadiusError::Conversion));
+    /// assert_eq!(SemicircleFilled::<i8>::try_new(100_u32), Err(RadiusError::TooLarge { max: 40 }));
+    /// ```
+    #[inline]
+    pub fn try_new<U>(radius: U) -> Result<Self, RadiusError<T>>
+    where
+        U: Unsigned + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+    {
+        let r = <T as TryFrom<U>>::try_from(radius).map_err(|_| RadiusError::Conversion)?;
+        Self::from_radius(r)
+    }
+
+    /// Creates a new `SemicircleFilled` iterator from a non-negative radius of type `T`.
+    #[inline]
+    fn from_radius(r: T) -> Result<Self, RadiusError<T>>
+    where
+        T: PrimInt + Signed,
+    {
+        let max = max_radius::<T>();
+        if r <= max {
+            let two = T::one() + T::one();
+            let start = Cursor {
+                x: -r,
+                y: T::zero(),
+                err: two - two * r,
+            };
+            return Ok(Self {
+                front: start.clone(),
+                back: start,
+                rows: r,
+            });
+        }
+
+        Err(RadiusError::TooLarge { max })
+    }
+}
+
+impl<T> Iterator for SemicircleFilled<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows.is_zero() {
+            return None;
+        }
+
+        let Cursor { x, y, .. } = self.front;
+        let two = T::one() + T::one();
+        self.rows = self.rows - T::one();
+        self.front.next_row(two, T::zero());
+
+        Some((x..-x, -(y + T::one())))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows_hint()
+    }
+}
+
+/// Returns the largest radius for which the error term cannot overflow a type whose maximum is `max`.
+///
+/// The error term and its increments stay within `3 * radius + 5`.
+const fn max_radius_for(max: i128) -> i128 {
+    (max - 5) / 3
+}
+
+/// Returns the largest radius for which the error term cannot overflow type `T`.
+#[inline]
+fn max_radius<T: PrimInt + Signed>() -> T {
+    let max = T::max_value().to_i128().unwrap_or_default();
+    <T as num::NumCast>::from(max_radius_for(max)).unwrap_or_else(T::zero)
+}
+
+macro_rules! impl_max_radius {
+    ($($t:ty),*) => {
+        $(
+            impl SemicircleFilled<$t> {
+                /// The largest radius for which the error term cannot overflow the coordinate type.
+                ///
+                /// The error term and its increments stay within `3 * radius + 5`.
+                pub const MAX_RADIUS: $t = max_radius_for(<$t>::MAX as i128) as $t;
+            }
+        )*
+    };
+}
+
+impl_max_radius!(i8, i16, i32, i64, i128, isize);
+
+impl<T: ToPrimitive> SemicircleFilled<T> {
+    /// Returns the number of remaining rows.
+    #[inline]
+    fn rows_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rows.to_usize();
+        (len.unwrap_or(usize::MAX), len)
+    }
+}
+
+impl<T> ExactSizeIterator for SemicircleFilled<T> where SemicircleFilled<T>: Iterator {}
+
+impl<T> FusedIterator for SemicircleFilled<T> where SemicircleFilled<T>: Iterator {}
+
+/// An iterator of successive coordinates of a circle outline, using Bresenham's algorithm.
+///
+/// Each item is the part of a row of the first quadrant that belongs to the outline.
+/// The outline consists exactly of the outermost pixels of the filled circle of the same radius.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct CircleOutline<T> {
+    semicircle: SemicircleFilled<T>,
+    row: Option<(Range<T>, T)>,
+}
+
+impl<T> CircleOutline<T> {
+    /// Creates a new `CircleOutline` iterator that generates pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic under the same conditions as [`SemicircleFilled::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - A non-negative integer representing the radius of the circle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::CircleOutline;
+    ///
+    /// let radius = 5_u32;
+    /// let outline_iter = CircleOutline::<i32>::new(radius);
+    /// let vec = outline_iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(4..5, -1), (4..5, -2), (3..4, -3), (2..3, -4), (0..2, -5)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(radius: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+        SemicircleFilled<T>: Iterator<Item = (Range<T>, T)>,
+    {
+        let mut semicircle = SemicircleFilled::new(radius);
+        let row = semicircle.next();
+
+        Self { semicircle, row }
+    }
+}
+
+impl<T> Iterator for CircleOutline<T>
+where
+    SemicircleFilled<T>: Iterator<Item = (Range<T>, T)>,
+    T: Zero + One + Sub<Output = T> + PartialOrd + Copy,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, y) = self.row.take()?;
+        self.row = self.semicircle.next();
+
+        let edge = range.end - T::one();
+        let start = match self.row {
+            Some((ref next_range, _)) if next_range.end < edge => next_range.end,
+            Some(_) => edge,
+            None => T::zero(),
+        };
+
+        Some((start..range.end, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // The semicircle is only iterated from the front, so its lower bound is exact.
+        let len = (self.semicircle.size_hint().0).saturating_add(usize::from(self.row.is_some()));
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for CircleOutline<T> where CircleOutline<T>: Iterator {}
+
+impl<T> FusedIterator for CircleOutline<T> where CircleOutline<T>: Iterator {}
+
+/// An iterator of successive coordinates of a filled semiellipse.
+///
+/// A pixel belongs to the ellipse if its center lies inside the ellipse.
+/// The rows and columns through the center are always filled to the full radii,
+/// so the ellipse spans exactly `2 * rx` by `2 * ry` pixels.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SemiellipseFilled<T> {
+    x: T,
+    y: T,
+    height: T,
+    rx_sq: i128,
+    ry_sq: i128,
+    bound: i128,
+}
+
+impl<T> SemiellipseFilled<T> {
+    /// Creates a new `SemiellipseFilled` iterator that generates pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a radius cannot be converted to type `T` or
+    ///
+    /// if the error term `8 * rx² * ry²` overflows `i128`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rx` - A non-negative integer representing the horizontal radius of the semiellipse.
+    /// * `ry` - A non-negative integer representing the vertical radius of the semiellipse.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::SemiellipseFilled;
+    ///
+    /// let semiellipse_iter = SemiellipseFilled::<i32>::new(10_u32, 4_u32);
+    /// let vec = semiellipse_iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-10..10, -1), (-9..9, -2), (-8..8, -3), (-5..5, -4)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(rx: U, ry: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U> + Zero + ToPrimitive + Default + Copy,
+        <T as TryFrom<U>>::Error: Debug,
+    {
+        let convert = |radius: U| {
+            let Ok(r) = <T as TryFrom<U>>::try_from(radius) else {
+                panic!(
+                    "{}",
+                    format_args!("Not possible to convert radius to {}", radius)
+                )
+            };
+            r
+        };
+        let (a, b) = (convert(rx), convert(ry));
+
+        if a.is_zero() || b.is_zero() {
+            return Self::default();
+        }
+
+        let (rx, ry) = (
+            a.to_i128().unwrap_or_default(),
+            b.to_i128().unwrap_or_default(),
+        );
+        let bound = rx
+            .checked_mul(ry)
+            .and_then(|ab| ab.checked_mul(2))
+            .and_then(|ab| ab.checked_mul(ab));
+        match bound {
+            Some(bound) if bound.checked_add(bound).is_some() => Self {
+                x: a,
+                y: T::zero(),
+                height: b,
+                rx_sq: rx * rx,
+                ry_sq: ry * ry,
+                bound,
+            },
+            _ => panic!("Radius is too large"),
+        }
+    }
+}
+
+impl<T> Iterator for SemiellipseFilled<T>
+where
+    T: Zero + One + Neg<Output = T> + Sub<Output = T> + PartialOrd + ToPrimitive + Copy,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.height {
+            return None;
+        }
+
+        if !self.y.is_zero() {
+            let wide = |v: T| v.to_i128().unwrap_or_default();
+            let dy = 2 * wide(self.y) + 1;
+            let err_y = self.rx_sq * dy * dy;
+
+            while self.x > T::one() {
+                let dx = 2 * wide(self.x) - 1;
+                if self.ry_sq * dx * dx + err_y <= self.bound {
+                    break;
+                }
+                self.x = self.x - T::one();
+            }
+        }
+
+        self.y = self.y + T::one();
+
+        Some((-self.x..self.x, -self.y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.y < self.height {
+            (self.height - self.y).to_usize()
+        } else {
+            Some(0)
+        };
+        (len.unwrap_or(usize::MAX), len)
+    }
+}
+
+impl<T> ExactSizeIterator for SemiellipseFilled<T> where SemiellipseFilled<T>: Iterator {}
+
+impl<T> FusedIterator for SemiellipseFilled<T> where SemiellipseFilled<T>: Iterator {}
+
+/// An iterator of successive coordinates of a filled semi-superellipse `|x / rx|^n + |y / ry|^n <= 1`.
+///
+/// The rows have the same form as those of [`SemicircleFilled`], so the same adapters apply.
+/// The exponent `2` equals the disc only because equal radii delegate to [`SemicircleFilled`],
+/// other radii delegate to [`SemiellipseFilled`]. Other exponents take the pixels whose centers
+/// lie inside, with at least two pixels in each row like the semiellipse.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuperellipseFilled<T> {
+    rows: SuperellipseRows<T>,
+}
+
+/// The rows of a superellipse, from the disc or the ellipse for the exponent `2`
+/// and from the pixel centers otherwise.
+#[derive(Debug, Clone, PartialEq)]
+enum SuperellipseRows<T> {
+    Disc(SemicircleFilled<T>),
+    Ellipse(SemiellipseFilled<T>),
+    General {
+        x: i128,
+        y: i128,
+        rx: i128,
+        ry: i128,
+        exponent: (u32, u32),
+    },
+}
+
+impl<T: Default> Default for SuperellipseFilled<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            rows: SuperellipseRows::Disc(SemicircleFilled::default()),
+        }
+    }
+}
+
+impl<T> SuperellipseFilled<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `SuperellipseFilled` iterator with an integer exponent that generates pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a radius cannot be converted to type `T`,
+    ///
+    /// if the radii are too large for the disc or the ellipse of the exponent `2` or
+    ///
+    /// if the exponent is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `rx` - A non-negative integer representing the horizontal radius of the superellipse.
+    /// * `ry` - A non-negative integer representing the vertical radius of the superellipse.
+    /// * `exponent` - A positive integer, `2` gives an ellipse, larger values a squircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{SemicircleFilled, SuperellipseFilled};
+    ///
+    /// let vec = SuperellipseFilled::<i32>::new(5_u32, 5_u32, 4).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-5..5, -1), (-5..5, -2), (-5..5, -3), (-5..5, -4), (-4..4, -5)]);
+    /// let disc = SemicircleFilled::<i32>::new(5_u32).collect::<Vec<_>>();
+    /// assert_eq!(SuperellipseFilled::<i32>::new(5_u32, 5_u32, 2).collect::<Vec<_>>(), disc);
+    /// ```
+    #[inline]
+    pub fn new<U>(rx: U, ry: U, exponent: u32) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U> + Default,
+        <T as TryFrom<U>>::Error: Debug,
+    {
+        Self::with_exponent(rx, ry, exponent, 1)
+    }
+
+    /// Creates a new `SuperellipseFilled` iterator with a rational exponent that generates pixel coordinates.
+    ///
+    /// The exponent is the fraction `numerator / denominator`, values below `1` give a concave star.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a radius cannot be converted to type `T`,
+    ///
+    /// if the radii are too large for the disc or the ellipse of the exponent `2` or
+    ///
+    /// if the numerator or the denominator is zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `rx` - A non-negative integer representing the horizontal radius of the superellipse.
+    /// * `ry` - A non-negative integer representing the vertical radius of the superellipse.
+    /// * `numerator` - The numerator of the exponent.
+    /// * `denominator` - The denominator of the exponent.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::SuperellipseFilled;
+    ///
+    /// let vec = SuperellipseFilled::<i32>::with_exponent(6_u32, 3_u32, 5, 2).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-6..6, -1), (-6..6, -2), (-4..4, -3)]);
+    /// ```
+    #[inline]
+    pub fn with_exponent<U>(rx: U, ry: U, numerator: u32, denominator: u32) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U> + Default,
+        <T as TryFrom<U>>::Error: Debug,
+    {
+        if numerator == 0 || denominator == 0 {
+            panic!("The exponent must be positive");
+        }
+
+        let convert = |radius: U| {
+            <T as TryFrom<U>>::try_from(radius).unwrap_or_else(|_| {
+                panic!(
+                    "{}",
+                    format_args!("Not possible to convert radius to {}", radius)
+                )
+            })
+        };
+        let (a, b) = (convert(rx), convert(ry));
+        let (mut p, mut q) = (numerator, denominator);
+        while q != 0 {
+            (p, q) = (q, p % q);
+        }
+        let exponent = (numerator / p, denominator / p);
+
+        let rows = match exponent {
+            (2, 1) if a == b => SuperellipseRows::Disc(SemicircleFilled::new(rx)),
+            (2, 1) => SuperellipseRows::Ellipse(SemiellipseFilled::new(rx, ry)),
+            _ => {
+                let wide = |v: T| v.to_i128().unwrap_or_default();
+                // A zero radius leaves no rows.
+                SuperellipseRows::General {
+                    x: wide(a),
+                    y: 0,
+                    rx: wide(a),
+                    ry: if a.is_zero() { 0 } else { wide(b) },
+                    exponent,
+                }
+            }
+        };
+
+        Self { rows }
+    }
+}
+
+/// Returns whether the center of the pixel left of `x` in the row `y` lies inside the superellipse.
+///
+/// An integer exponent is compared exactly while the powers fit, a rational one with a tolerance.
+#[inline]
+fn superellipse_contains(x: i128, y: i128, (rx, ry): (i128, i128), (p, q): (u32, u32)) -> bool {
+    let (u, v) = (2 * x - 1, 2 * y + 1);
+    if q == 1 {
+        let sum = (ry * u)
+            .checked_pow(p)
+            .zip((rx * v).checked_pow(p))
+            .and_then(|(a, b)| a.checked_add(b));
+        if let Some((sum, bound)) = sum.zip((2 * rx * ry).checked_pow(p)) {
+            return sum <= bound;
+        }
+    }
+
+    let n = f64::from(p) / f64::from(q);
+    let (u, v) = (u as f64 / (2 * rx) as f64, v as f64 / (2 * ry) as f64);
+
+    powf(u, n) + powf(v, n) <= 1.0 + 1e-12
+}
+
+impl<T> Iterator for SuperellipseFilled<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        match &mut self.rows {
+            SuperellipseRows::Disc(disc) => disc.next(),
+            SuperellipseRows::Ellipse(ellipse) => ellipse.next(),
+            SuperellipseRows::General {
+                x,
+                y,
+                rx,
+                ry,
+                exponent,
+            } => {
+                if *y >= *ry {
+                    return None;
+                }
+
+                while *x > 1 && !superellipse_contains(*x, *y, (*rx, *ry), *exponent) {
+                    *x -= 1;
+                }
+                *y += 1;
+
+                Some((narrow(-*x)..narrow(*x), narrow(-*y)))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.rows {
+            SuperellipseRows::Disc(disc) => disc.size_hint(),
+            SuperellipseRows::Ellipse(ellipse) => ellipse.size_hint(),
+            SuperellipseRows::General { y, ry, .. } => {
+                let len = (ry - y).max(0).to_usize();
+                (len.unwrap_or(usize::MAX), len)
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for SuperellipseFilled<T> where SuperellipseFilled<T>: Iterator {}
+
+/// An iterator of successive coordinates of an ellipse outline.
+///
+/// Each item is the part of a row of the first quadrant that belongs to the outline.
+/// The outline is an 8-connected ring of the outermost pixels of the filled ellipse
+/// of the same radii, without corner pixels that do not contribute to the connectivity.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct EllipseOutline<T> {
+    semiellipse: SemiellipseFilled<T>,
+    row: Option<(Range<T>, T)>,
+    width: T,
+}
+
+impl<T> EllipseOutline<T> {
+    /// Creates a new `EllipseOutline` iterator that generates pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic under the same conditions as [`SemiellipseFilled::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `rx` - A non-negative integer representing the horizontal radius of the ellipse.
+    /// * `ry` - A non-negative integer representing the vertical radius of the ellipse.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::EllipseOutline;
+    ///
+    /// let outline_iter = EllipseOutline::<i32>::new(10_u32, 4_u32);
+    /// let vec = outline_iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(9..10, -1), (8..9, -2), (5..8, -3), (0..5, -4)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(rx: U, ry: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U> + Zero + ToPrimitive + Default + Copy,
+        <T as TryFrom<U>>::Error: Debug,
+        SemiellipseFilled<T>: Iterator<Item = (Range<T>, T)>,
+    {
+        let mut semiellipse = SemiellipseFilled::new(rx, ry);
+        let row = semiellipse.next();
+
+        Self {
+            semiellipse,
+            row,
+            width: T::zero(),
+        }
+    }
+}
+
+impl<T> Iterator for EllipseOutline<T>
+where
+    SemiellipseFilled<T>: Iterator<Item = (Range<T>, T)>,
+    T: Zero + One + Sub<Output = T> + PartialOrd + Copy,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (range, y) = self.row.take()?;
+        self.row = self.semiellipse.next();
+
+        let edge = range.end - T::one();
+        let start = match self.row {
+            Some((ref next_range, _)) if next_range.end < edge => next_range.end,
+            Some(_) => edge,
+            None => T::zero(),
+        };
+
+        // The outer pixel is a redundant corner if the ring continues
+        // both straight inwards and along the row.
+        let end = if start < edge && self.width == range.end {
+            edge
+        } else {
+            range.end
+        };
+        self.width = range.end;
+
+        Some((start..end, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.semiellipse.size_hint().0 + usize::from(self.row.is_some());
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for EllipseOutline<T> where EllipseOutline<T>: Iterator {}
+
+impl<T> FusedIterator for EllipseOutline<T> where EllipseOutline<T>: Iterator {}
+
+/// An iterator of successive coordinates of a filled semicircle centered on a pixel, using Bresenham's algorithm.
+///
+/// Unlike [`SemicircleFilled`], the circle has an odd diameter of `2 * radius + 1`.
+/// The rows are yielded as inclusive spans, the top semicircle includes the center row.
+///
+/// The adapters of [`Rasterization`] mirror its rows to `-y` and leave the center row alone,
+/// see [`Row`].
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct SemicircleCentered<T> {
+    cursor: Cursor<T>,
+}
+
+impl<T> SemicircleCentered<T> {
+    /// Creates a new `SemicircleCentered` iterator that generates pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic under the same conditions as [`SemicircleFilled::new`].
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - A non-negative integer representing the radius of the semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::SemicircleCentered;
+    ///
+    /// let radius = 2_u32;
+    /// let semicircle_iter = SemicircleCentered::<i32>::new(radius);
+    /// let vec = semicircle_iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..=2, 0), (-2..=2, -1), (-1..=1, -2)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(radius: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+    {
+        let SemicircleFilled { front, .. } = SemicircleFilled::new(radius);
+
+        Self { cursor: front }
+    }
+}
+
+impl<T> Iterator for SemicircleCentered<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (RangeInclusive<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let Cursor { x, y, .. } = self.cursor;
+
+        if x > T::zero() {
+            return None;
+        }
+
+        self.cursor.next_row(T::one(), T::one());
+
+        Some((x..=-x, -y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Cursor { x, y, err } = &self.cursor;
+        let (Some(x), Some(y), Some(err)) = (x.to_i128(), y.to_i128(), err.to_i128()) else {
+            return (0, None);
+        };
+
+        if x > 0 {
+            return (0, Some(0));
+        }
+
+        // The error term keeps `x² + 2x + y² + 2y + 2 - err` equal to `r²`.
+        let Some(r) = x
+            .checked_mul(x + 2)
+            .zip(y.checked_mul(y + 2))
+            .and_then(|(x_sq, y_sq)| x_sq.checked_add(y_sq))
+            .map(|r_sq| (r_sq + 2 - err).sqrt())
+        else {
+            return (0, None);
+        };
+        let Ok(len) = usize::try_from(r - y + 1) else {
+            return (usize::MAX, None);
+        };
+
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for SemicircleCentered<T> where SemicircleCentered<T>: Iterator {}
+
+impl<T> FusedIterator for SemicircleCentered<T> where SemicircleCentered<T>: Iterator {}
+
+/// An iterator of the spans of a filled ring, the pixels of an outer disc that are not in an inner disc.
+///
+/// The discs are those of [`SemicircleFilled`], the rows of the top half are yielded
+/// as one span, or as a left and a right span where the row crosses the inner disc.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Annulus<T> {
+    outer: SemicircleFilled<T>,
+    inner: SemicircleFilled<T>,
+    right: Option<(Range<T>, T)>,
+}
+
+impl<T> Annulus<T> {
+    /// Creates a new `Annulus` iterator that generates spans of pixel coordinates.
+    ///
+    /// The ring is empty if the inner radius is not less than the outer radius.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a radius cannot be converted to type `T` or
+    ///
+    /// if a radius exceeds [`SemicircleFilled::MAX_RADIUS`] of type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `outer_radius` - A non-negative integer representing the outer radius of the ring.
+    /// * `inner_radius` - A non-negative integer representing the radius of the hole.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Annulus;
+    ///
+    /// let vec = Annulus::<i32>::new(4_u32, 2_u32).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-4..-2, -1), (2..4, -1), (-4..-1, -2), (1..4, -2), (-3..3, -3), (-2..2, -4)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(outer_radius: U, inner_radius: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+    {
+        Self {
+            outer: SemicircleFilled::new(outer_radius),
+            inner: SemicircleFilled::new(inner_radius),
+            right: None,
+        }
+    }
+}
+
+impl<T> Annulus<T> {
+    /// Creates a new `Annulus` iterator for a circle stroke of the given width.
+    ///
+    /// The stroke is the ring between two [`SemicircleFilled`] discs whose radii differ by
+    /// the width, so the pixels have no gaps and no overlaps. An inside stroke wider than
+    /// the radius fills the whole disc.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the radius or the width cannot be converted to type `T` or
+    ///
+    /// if the outer radius of the stroke exceeds [`SemicircleFilled::MAX_RADIUS`] of type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - A non-negative integer representing the nominal radius of the circle.
+    /// * `width` - A non-negative integer representing the width of the stroke.
+    /// * `alignment` - The position of the stroke relative to the nominal radius.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Annulus, StrokeAlignment};
+    ///
+    /// let inside = Annulus::<i32>::stroke(4_u32, 2_u32, StrokeAlignment::Inside);
+    /// assert_eq!(inside, Annulus::<i32>::new(4_u32, 2_u32));
+    /// let centered = Annulus::<i32>::stroke(4_u32, 3_u32, StrokeAlignment::Centered);
+    /// assert_eq!(centered, Annulus::<i32>::new(5_u32, 2_u32));
+    /// let outside = Annulus::<i32>::stroke(4_u32, 2_u32, StrokeAlignment::Outside);
+    /// assert_eq!(outside, Annulus::<i32>::new(6_u32, 4_u32));
+    /// ```
+    #[inline]
+    pub fn stroke<U>(radius: U, width: U, alignment: StrokeAlignment) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+    {
+        let convert = |value: U, name: &str| {
+            <T as TryFrom<U>>::try_from(value).unwrap_or_else(|_| {
+                panic!(
+                    "{}",
+                    format_args!("Not possible to convert {} to {}", name, value)
+                )
+            })
+        };
+        let r = convert(radius, "radius");
+        let w = convert(width, "width");
+        let two = T::one() + T::one();
+        let (inside, outside) = match alignment {
+            StrokeAlignment::Inside => (w, T::zero()),
+            StrokeAlignment::Centered => (w - w / two, w / two),
+            StrokeAlignment::Outside => (T::zero(), w),
+        };
+        let inner = (r - inside).max(T::zero());
+        let outer = r.checked_add(&outside).map(SemicircleFilled::from_radius);
+        let (Some(Ok(outer)), Ok(inner)) = (outer, SemicircleFilled::from_radius(inner)) else {
+            panic!("Radius is too large");
+        };
+
+        Self {
+            outer,
+            inner,
+            right: None,
+        }
+    }
+}
+
+impl<T> Iterator for Annulus<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(row) = self.right.take() {
+            return Some(row);
+        }
+
+        // The discs yield one row for each y, starting from the same row.
+        loop {
+            let (outer, y) = self.outer.next()?;
+            let Some((inner, _)) = self.inner.next() else {
+                return Some((outer, y));
+            };
+
+            if inner.end < outer.end {
+                self.right = Some((inner.end..outer.end, y));
+                return Some((outer.start..inner.start, y));
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let right = usize::from(self.right.is_some());
+        let (lower, upper) = self.outer.size_hint();
+        let lower = lower.saturating_sub(self.inner.size_hint().1.unwrap_or(usize::MAX));
+        let upper = upper
+            .and_then(|upper| upper.checked_mul(2))
+            .and_then(|upper| upper.checked_add(right));
+
+        (lower + right, upper)
+    }
+}
+
+impl<T> FusedIterator for Annulus<T> where Annulus<T>: Iterator {}
+
+/// An iterator of the spans of a filled rectangle with rounded corners.
+///
+/// The corners are the quarters of [`SemicircleFilled`] discs. The rectangle spans
+/// `0..width` and `0..height`, its rows are yielded from top to bottom.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RoundedRect<T> {
+    y: T,
+    width: T,
+    height: T,
+    radii: [T; 4],
+    corners: [SemicircleFilled<T>; 4],
+}
+
+impl<T> RoundedRect<T> {
+    /// Creates a new `RoundedRect` iterator with the same radius for all corners.
+    ///
+    /// A square with the side of twice the radius has the pixels of the full circle,
+    /// a wider rectangle those of the long circle.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a value cannot be converted to type `T`,
+    ///
+    /// if the radius exceeds [`SemicircleFilled::MAX_RADIUS`] of type `T` or
+    ///
+    /// if the corners do not fit into the rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - A non-negative integer representing the width of the rectangle.
+    /// * `height` - A non-negative integer representing the height of the rectangle.
+    /// * `radius` - A non-negative integer representing the radius of the corners.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::RoundedRect;
+    ///
+    /// let vec = RoundedRect::<i32>::new(6_u32, 4_u32, 2_u32).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(1..5, 0), (0..6, 1), (0..6, 2), (1..5, 3)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(width: U, height: U, radius: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+    {
+        Self::with_radii(width, height, [radius; 4])
+    }
+
+    /// Creates a new `RoundedRect` iterator with a radius for each corner.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a value cannot be converted to type `T`,
+    ///
+    /// if a radius exceeds [`SemicircleFilled::MAX_RADIUS`] of type `T` or
+    ///
+    /// if the corners do not fit into the rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - A non-negative integer representing the width of the rectangle.
+    /// * `height` - A non-negative integer representing the height of the rectangle.
+    /// * `radii` - The radii of the top left, top right, bottom right and bottom left corners.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::RoundedRect;
+    ///
+    /// let vec = RoundedRect::<i32>::with_radii(5_u32, 3_u32, [3, 0, 0, 0]).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(1..5, 0), (0..5, 1), (0..5, 2)]);
+    /// ```
+    #[inline]
+    pub fn with_radii<U>(width: U, height: U, radii: [U; 4]) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: PrimInt + Signed + TryFrom<U>,
+    {
+        let convert = |value: U, name: &str| {
+            <T as TryFrom<U>>::try_from(value).unwrap_or_else(|_| {
+                panic!(
+                    "{}",
+                    format_args!("Not possible to convert {} to {}", name, value)
+                )
+            })
+        };
+        let (width, height) = (convert(width, "width"), convert(height, "height"));
+        let radii = radii.map(|radius| convert(radius, "radius"));
+        let [top_left, top_right, bottom_right, bottom_left] = radii;
+        let fits = |a: T, b: T, side: T| a.checked_add(&b).is_some_and(|sum| sum <= side);
+        if !(fits(top_left, top_right, width)
+            && fits(bottom_left, bottom_right, width)
+            && fits(top_left, bottom_left, height)
+            && fits(top_right, bottom_right, height))
+        {
+            panic!("The corners do not fit into the rectangle");
+        }
+
+        Self {
+            y: T::zero(),
+            width,
+            height,
+            radii,
+            corners: radii.map(|radius| {
+                SemicircleFilled::from_radius(radius)
+                    .unwrap_or_else(|_| panic!("Radius is too large"))
+            }),
+        }
+    }
+}
+
+impl<T> Iterator for RoundedRect<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.height {
+            return None;
+        }
+
+        let [top_left, top_right, bottom_right, bottom_left] = &mut self.corners;
+        let [top_left_radius, top_right_radius, bottom_right_radius, bottom_left_radius] =
+            self.radii;
+        let from_bottom = self.height - self.y;
+        let left = corner_inset(
+            (top_left, top_left_radius),
+            (bottom_left, bottom_left_radius),
+            self.y,
+            from_bottom,
+        );
+        let right = corner_inset(
+            (top_right, top_right_radius),
+            (bottom_right, bottom_right_radius),
+            self.y,
+            from_bottom,
+        );
+        let y = self.y;
+        self.y = self.y + T::one();
+
+        Some((left..self.width - right, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.height - self.y).to_usize().unwrap_or_default();
+
+        (len, Some(len))
+    }
+}
+
+/// Returns how far the corners indent the row `y` of a rounded rectangle.
+///
+/// The top corner takes the rows of its disc from the top, the bottom corner from the middle.
+#[inline]
+fn corner_inset<T>(
+    (top, top_radius): (&mut SemicircleFilled<T>, T),
+    (bottom, bottom_radius): (&mut SemicircleFilled<T>, T),
+    y: T,
+    from_bottom: T,
+) -> T
+where
+    T: PrimInt + Signed,
+{
+    let row = if y < top_radius {
+        top.next_back().map(|(span, _)| top_radius - span.end)
+    } else if from_bottom <= bottom_radius {
+        bottom.next().map(|(span, _)| bottom_radius - span.end)
+    } else {
+        None
+    };
+
+    row.unwrap_or_else(T::zero)
+}
+
+impl<T> ExactSizeIterator for RoundedRect<T> where RoundedRect<T>: Iterator {}
+
+impl<T> FusedIterator for RoundedRect<T> where RoundedRect<T>: Iterator {}
+
+/// An iterator of the spans of a filled rectangle or a rectangular frame, yielded row by row from top to bottom.
+///
+/// The rectangle has its top left corner at the origin, the rows of a frame between its
+/// top and bottom borders consist of a left and a right span.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Rectangle<T> {
+    y: T,
+    width: T,
+    height: T,
+    border: T,
+    right: Option<(Range<T>, T)>,
+}
+
+impl<T> Rectangle<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `Rectangle` iterator of a filled rectangle.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a value cannot be converted to type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - A non-negative integer representing the width of the rectangle.
+    /// * `height` - A non-negative integer representing the height of the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Rectangle;
+    ///
+    /// let vec = Rectangle::<i32>::new(3_u32, 2_u32).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..3, 0), (0..3, 1)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(width: U, height: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U>,
+    {
+        Self::frame(width, height, height)
+    }
+
+    /// Creates a new `Rectangle` iterator of a frame with a border inside the rectangle.
+    ///
+    /// A border of at least half the width or the height fills the whole rectangle, a border of zero leaves it empty.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a value cannot be converted to type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - A non-negative integer representing the width of the rectangle.
+    /// * `height` - A non-negative integer representing the height of the rectangle.
+    /// * `border` - A non-negative integer representing the width of the border.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Rectangle;
+    ///
+    /// let vec = Rectangle::<i32>::frame(4_u32, 4_u32, 1_u32).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..4, 0), (0..1, 1), (3..4, 1), (0..1, 2), (3..4, 2), (0..4, 3)]);
+    /// ```
+    #[inline]
+    pub fn frame<U>(width: U, height: U, border: U) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U>,
+    {
+        let convert = |value: U, name: &str| {
+            <T as TryFrom<U>>::try_from(value).unwrap_or_else(|_| {
+                panic!(
+                    "{}",
+                    format_args!("Not possible to convert {} to {}", name, value)
+                )
+            })
+        };
+        let (width, height) = (convert(width, "width"), convert(height, "height"));
+        let border = convert(border, "border");
+        // A border that meets itself fills the rows between.
+        let two = T::one() + T::one();
+        let border = match border.checked_mul(&two) {
+            Some(double) if double < width && double < height => border,
+            _ => height,
+        };
+
+        Self {
+            y: if width.is_zero() || border.is_zero() {
+                height
+            } else {
+                T::zero()
+            },
+            width,
+            height,
+            border,
+            right: None,
+        }
+    }
+
+    /// Returns whether the row `y` is a full row of the rectangle.
+    #[inline]
+    fn is_full(&self, y: T) -> bool {
+        y < self.border || y >= self.height - self.border
+    }
+}
+
+impl<T> Iterator for Rectangle<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(right) = self.right.take() {
+            return Some(right);
+        }
+
+        if self.y >= self.height {
+            return None;
+        }
+
+        let y = self.y;
+        self.y = self.y + T::one();
+        if self.is_full(y) {
+            return Some((T::zero()..self.width, y));
+        }
+
+        self.right = Some((self.width - self.border..self.width, y));
+
+        Some((T::zero()..self.border, y))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rows = (self.height - self.y).to_usize().unwrap_or_default();
+        let border = self.border.to_usize().unwrap_or_default();
+        // The rows left between the top and the bottom border have two spans.
+        let top = (self.border - self.y).to_usize().unwrap_or_default();
+        let split = rows.saturating_sub(top.min(rows) + border.min(rows));
+        let len = rows + split + usize::from(self.right.is_some());
+
+        (len, Some(len))
+    }
+}
+
+impl<T> ExactSizeIterator for Rectangle<T> where Rectangle<T>: Iterator {}
+
+impl<T> FusedIterator for Rectangle<T> where Rectangle<T>: Iterator {}
+
+/// An iterator of successive coordinates of a line segment, using Bresenham's algorithm.
+///
+/// The line runs from the start point to the end point in any direction. Iterating from the
+/// back yields the same pixels in the reversed order.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Line<T> {
+    front: (T, T),
+    back: (T, T),
+    front_err: u128,
+    back_err: u128,
+    step: (T, T),
+    major: u128,
+    minor: u128,
+    steep: bool,
+    steps: Option<u128>,
+}
+
+impl<T> Line<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `Line` iterator that generates pixel coordinates including the end point.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first point of the line.
+    /// * `end` - The last point of the line.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Line;
+    ///
+    /// let vec = Line::<i32>::new((0, 0), (5, 2)).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)]);
+    /// let vec = Line::<i32>::new((0, 0), (5, 2)).rev().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(5, 2), (4, 2), (3, 1), (2, 1), (1, 0), (0, 0)]);
+    /// ```
+    #[inline]
+    pub fn new(start: (T, T), end: (T, T)) -> Self {
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let signum = |a: T, b: T| match a.cmp(&b) {
+            Ordering::Less => T::one(),
+            Ordering::Equal => T::zero(),
+            Ordering::Greater => -T::one(),
+        };
+        let (dx, dy) = (
+            wide(end.0).abs_diff(wide(start.0)),
+            wide(end.1).abs_diff(wide(start.1)),
+        );
+        let steep = dy > dx;
+        let (major, minor) = if steep { (dy, dx) } else { (dx, dy) };
+
+        Self {
+            front: start,
+            back: end,
+            front_err: major / 2,
+            back_err: major / 2,
+            step: (signum(start.0, end.0), signum(start.1, end.1)),
+            major,
+            minor,
+            steep,
+            steps: Some(major),
+        }
+    }
+
+    /// Creates a new `Line` iterator that generates pixel coordinates excluding the end point.
+    ///
+    /// The pixels are those of [`Line::new`] without the last one, so joined lines
+    /// do not draw the shared points twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first point of the line.
+    /// * `end` - The point after the last point of the line.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Line;
+    ///
+    /// let vec = Line::<i32>::new_exclusive((0, 0), (-1, 3)).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (0, 1), (-1, 2)]);
+    /// ```
+    #[inline]
+    pub fn new_exclusive(start: (T, T), end: (T, T)) -> Self {
+        let mut line = Self::new(start, end);
+        line.steps = line.steps.and_then(|steps| steps.checked_sub(1));
+        if line.steps.is_some() {
+            (line.back, line.back_err) = line.step(line.back, line.back_err, false);
+        }
+
+        line
+    }
+
+    /// Returns the point one pixel forward or backward along the line with its error term.
+    ///
+    /// The error term of the pixel `i` is `(i * minor + major / 2) % major`,
+    /// so both ends of the line walk the same pixels.
+    #[inline]
+    fn step(&self, (x, y): (T, T), err: u128, forward: bool) -> ((T, T), u128) {
+        let (sx, sy) = if forward {
+            self.step
+        } else {
+            (-self.step.0, -self.step.1)
+        };
+        let carry = self.major - self.minor;
+        let (err, minor_step) = match forward {
+            true if err >= carry => (err - carry, true),
+            true => (err + self.minor, false),
+            false if err < self.minor => (err + carry, true),
+            false => (err - self.minor, false),
+        };
+
+        match (self.steep, minor_step) {
+            (false, false) => ((x + sx, y), err),
+            (true, false) => ((x, y + sy), err),
+            (_, true) => ((x + sx, y + sy), err),
+        }
+    }
+}
+
+impl<T> Iterator for Line<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.front;
+        self.steps = self.steps?.checked_sub(1);
+        if self.steps.is_some() {
+            (self.front, self.front_err) = self.step(self.front, self.front_err, true);
+        }
+
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = match self.steps {
+            Some(steps) => usize::try_from(steps)
+                .ok()
+                .and_then(|steps| steps.checked_add(1)),
+            None => Some(0),
+        };
+
+        match len {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Line<T>
+where
+    T: PrimInt + Signed,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let point = self.back;
+        self.steps = self.steps?.checked_sub(1);
+        if self.steps.is_some() {
+            (self.back, self.back_err) = self.step(self.back, self.back_err, false);
+        }
+
+        Some(point)
+    }
+}
+
+impl<T> ExactSizeIterator for Line<T> where Line<T>: Iterator {}
+
+impl<T> FusedIterator for Line<T> where Line<T>: Iterator {}
+
+/// An iterator of successive coordinates of connected line segments over a slice of points.
+///
+/// The segments are those of [`Line`], the pixel at each joint is yielded once.
+/// A closed path also connects the last point to the first one.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Polyline<'a, T> {
+    points: &'a [(T, T)],
+    closed: bool,
+    index: usize,
+    line: Line<T>,
+    tail: Option<(T, T)>,
+}
+
+impl<'a, T> Polyline<'a, T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `Polyline` iterator that generates pixel coordinates of an open path.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points of the path.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Polyline;
+    ///
+    /// let points = [(0, 0), (2, 0), (2, 2)];
+    /// let vec = Polyline::<i32>::new(&points).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+    /// ```
+    #[inline]
+    pub fn new(points: &'a [(T, T)]) -> Self {
+        let zero = (T::zero(), T::zero());
+
+        Self {
+            points,
+            closed: false,
+            index: 0,
+            line: Line::new_exclusive(zero, zero),
+            tail: points.last().copied(),
+        }
+    }
+
+    /// Creates a new `Polyline` iterator that generates pixel coordinates of a polygon outline.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The vertices of the polygon.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Polyline;
+    ///
+    /// let points = [(0, 0), (2, 0), (2, 2)];
+    /// let vec = Polyline::<i32>::new_closed(&points).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (1, 1)]);
+    /// ```
+    #[inline]
+    pub fn new_closed(points: &'a [(T, T)]) -> Self {
+        // The segments of a closed path of a single pixel are all empty.
+        let first = points.first().copied();
+        let tail = first.filter(|first| points.iter().all(|point| point == first));
+        let zero = (T::zero(), T::zero());
+
+        Self {
+            points,
+            closed: true,
+            index: 0,
+            line: Line::new_exclusive(zero, zero),
+            tail,
+        }
+    }
+
+    /// Returns the number of the segments of the path.
+    #[inline]
+    fn segments(&self) -> usize {
+        match self.closed {
+            true => self.points.len(),
+            false => self.points.len().saturating_sub(1),
+        }
+    }
+
+    /// Returns the end points of the segment `index`.
+    #[inline]
+    fn segment(&self, index: usize) -> ((T, T), (T, T)) {
+        let end = (index + 1) % self.points.len();
+
+        (self.points[index], self.points[end])
+    }
+}
+
+impl<'a, T> Iterator for Polyline<'a, T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(point) = self.line.next() {
+                return Some(point);
+            }
+
+            if self.index >= self.segments() {
+                return self.tail.take();
+            }
+
+            let (start, end) = self.segment(self.index);
+            self.line = Line::new_exclusive(start, end);
+            self.index += 1;
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let line = self
+            .line
+            .size_hint()
+            .1
+            .and_then(|len| len.checked_add(usize::from(self.tail.is_some())));
+        let len = line.and_then(|line| {
+            (self.index..self.segments())
+                .map(|index| {
+                    let (start, end) = self.segment(index);
+                    let dx = wide(end.0).abs_diff(wide(start.0));
+                    let dy = wide(end.1).abs_diff(wide(start.1));
+                    dx.max(dy)
+                })
+                .try_fold(line, |len, segment| {
+                    len.checked_add(usize::try_from(segment).ok()?)
+                })
+        });
+
+        match len {
+            Some(len) => (len, Some(len)),
+            None => (usize::MAX, None),
+        }
+    }
+}
+
+impl<'a, T> FusedIterator for Polyline<'a, T> where Polyline<'a, T>: Iterator {}
+
+/// The vertices of a Bézier curve flattened into a polyline.
+///
+/// A quadratic curve is raised to a cubic one, the control points are tripled to stay integers.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Flattening {
+    points: [(i128, i128); 4],
+    steps: i128,
+}
+
+impl Flattening {
+    /// Creates the flattening from tripled cubic control points, the coordinates of which do not exceed `3 * 2^32`.
+    #[inline]
+    fn new(points: [(i128, i128); 4]) -> Self {
+        let [p0, p1, p2, p3] = points;
+        let curvature = [
+            p0.0 - 2 * p1.0 + p2.0,
+            p0.1 - 2 * p1.1 + p2.1,
+            p1.0 - 2 * p2.0 + p3.0,
+            p1.1 - 2 * p2.1 + p3.1,
+        ]
+        .into_iter()
+        .map(i128::abs)
+        .max()
+        .unwrap_or_default();
+
+        // The polyline deviates less than half a pixel from the curve.
+        Self {
+            points,
+            steps: curvature.sqrt() + 1,
+        }
+    }
+
+    /// Creates the flattening of a quadratic curve.
+    #[inline]
+    fn quadratic<T>(p0: (T, T), p1: (T, T), p2: (T, T)) -> Self
+    where
+        T: PrimInt + Signed,
+    {
+        let [p0, p1, p2] = Self::wide([p0, p1, p2]);
+
+        Self::new([
+            (3 * p0.0, 3 * p0.1),
+            (p0.0 + 2 * p1.0, p0.1 + 2 * p1.1),
+            (2 * p1.0 + p2.0, 2 * p1.1 + p2.1),
+            (3 * p2.0, 3 * p2.1),
+        ])
+    }
+
+    /// Creates the flattening of a cubic curve.
+    #[inline]
+    fn cubic<T>(p0: (T, T), p1: (T, T), p2: (T, T), p3: (T, T)) -> Self
+    where
+        T: PrimInt + Signed,
+    {
+        Self::new(Self::wide([p0, p1, p2, p3]).map(|p| (3 * p.0, 3 * p.1)))
+    }
+
+    /// Converts the control points into wide integers.
+    #[inline]
+    fn wide<T, const N: usize>(points: [(T, T); N]) -> [(i128, i128); N]
+    where
+        T: PrimInt + Signed,
+    {
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let limit = 1_i128 << 32;
+        if points
+            .iter()
+            .any(|p| wide(p.0).abs() > limit || wide(p.1).abs() > limit)
+        {
+            panic!("The curve is too large");
+        }
+
+        points.map(|p| (wide(p.0), wide(p.1)))
+    }
+
+    /// Returns the vertex `i` of the polyline, the point of the curve rounded to the nearest pixel.
+    #[inline]
+    fn vertex(&self, i: i128) -> (i128, i128) {
+        let [p0, p1, p2, p3] = self.points;
+        let (n, u) = (self.steps, self.steps - i);
+        let (c0, c1, c2, c3) = (u * u * u, 3 * u * u * i, 3 * u * i * i, i * i * i);
+        let den = 3 * n * n * n;
+        let round = |num: i128| (2 * num + den).div_euclid(2 * den);
+
+        (
+            round(c0 * p0.0 + c1 * p1.0 + c2 * p2.0 + c3 * p3.0),
+            round(c0 * p0.1 + c1 * p1.1 + c2 * p2.1 + c3 * p3.1),
+        )
+    }
+
+    /// Returns the edges of the polyline closed by the chord from the last vertex to the first one.
+    #[inline]
+    fn edges(&self) -> impl Iterator<Item = ((i128, i128), (i128, i128))> + '_ {
+        (0..=self.steps).map(|i| (self.vertex(i), self.vertex((i + 1) % (self.steps + 1))))
+    }
+}
+
+/// An iterator of the pixels of a quadratic or cubic Bézier curve.
+///
+/// The curve is flattened into a polyline that deviates less than half a pixel from it,
+/// the pixels are 8-connected and a pixel is not repeated unless the curve crosses or retraces itself.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bezier<T> {
+    curve: Flattening,
+    index: i128,
+    line: Line<T>,
+    tail: Option<(T, T)>,
+    last: Option<(T, T)>,
+    pending: Option<(T, T)>,
+}
+
+impl<T> Bezier<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `Bezier` iterator of a quadratic curve that generates pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the coordinates of a control point exceed `2^32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first point of the curve.
+    /// * `control` - The control point.
+    /// * `end` - The last point of the curve.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Bezier;
+    ///
+    /// let vec = Bezier::<i32>::new_quadratic((0, 0), (3, 6), (6, 0)).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (1, 1), (2, 2), (3, 3), (4, 2), (5, 2), (6, 1), (6, 0)]);
+    /// ```
+    #[inline]
+    pub fn new_quadratic(start: (T, T), control: (T, T), end: (T, T)) -> Self {
+        Self::with_curve(Flattening::quadratic(start, control, end), end)
+    }
+
+    /// Creates a new `Bezier` iterator of a cubic curve that generates pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the coordinates of a control point exceed `2^32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first point of the curve.
+    /// * `first` - The control point next to the start.
+    /// * `second` - The control point next to the end.
+    /// * `end` - The last point of the curve.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Bezier;
+    ///
+    /// let vec = Bezier::<i32>::new_cubic((0, 0), (0, 4), (4, 4), (4, 0)).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (1, 1), (1, 2), (2, 3), (3, 2), (4, 1), (4, 0)]);
+    /// ```
+    #[inline]
+    pub fn new_cubic(start: (T, T), first: (T, T), second: (T, T), end: (T, T)) -> Self {
+        Self::with_curve(Flattening::cubic(start, first, second, end), end)
+    }
+
+    #[inline]
+    fn with_curve(curve: Flattening, end: (T, T)) -> Self {
+        Self {
+            curve,
+            index: 0,
+            line: Line::new_exclusive((T::zero(), T::zero()), (T::zero(), T::zero())),
+            tail: Some(end),
+            last: None,
+            pending: None,
+        }
+    }
+
+    /// Returns the next pixel of the polyline.
+    #[inline]
+    fn step(&mut self) -> Option<(T, T)> {
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        loop {
+            if let Some(point) = self.line.next() {
+                return Some(point);
+            }
+
+            if self.index >= self.curve.steps {
+                return self.tail.take();
+            }
+
+            let (a, b) = (
+                self.curve.vertex(self.index),
+                self.curve.vertex(self.index + 1),
+            );
+            self.index += 1;
+            self.line = Line::new_exclusive((narrow(a.0), narrow(a.1)), (narrow(b.0), narrow(b.1)));
+        }
+    }
+}
+
+impl<T> Iterator for Bezier<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(point) = self.step() else {
+                self.last = self.pending.take();
+                return self.last;
+            };
+
+            // A pixel that turns back to the last one is a spur, it is dropped with the return.
+            if Some(point) == self.last {
+                self.pending = None;
+                continue;
+            }
+
+            if let Some(pending) = self.pending.replace(point) {
+                self.last = Some(pending);
+                return self.last;
+            }
+        }
+    }
+}
+
+impl<T> FusedIterator for Bezier<T> where Bezier<T>: Iterator {}
+
+/// An iterator of the spans of a filled polygon over a slice of vertices, yielded row by row from top to bottom.
+///
+/// A pixel is filled if its center lies inside the polygon by the fill rule. A center on
+/// a left edge is inside and on a right edge outside, so polygons that share an edge
+/// neither overlap nor leave gaps. The centers never lie on the top or bottom edges.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PolygonFilled<'a, T> {
+    points: &'a [(T, T)],
+    scan: Scanline,
+}
+
+/// The state of a scan of the rows of a polygon from the top to the bottom.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Scanline {
+    rule: FillRule,
+    y: i128,
+    end_y: i128,
+    cursor: Option<i128>,
+    winding: i128,
+    start: i128,
+}
+
+impl Scanline {
+    /// Returns the next span inside the polygon, from the crossings of its rows.
+    #[inline]
+    fn next<F>(&mut self, crossing: F) -> Option<(Range<i128>, i128)>
+    where
+        F: Fn(i128, Option<i128>) -> Option<(i128, i128)>,
+    {
+        while self.y < self.end_y {
+            let Some((x, dir)) = crossing(self.y, self.cursor) else {
+                self.y += 1;
+                self.cursor = None;
+                self.winding = 0;
+                continue;
+            };
+
+            let was_inside = self.inside(self.winding);
+            self.winding += dir;
+            self.cursor = Some(x);
+            match (was_inside, self.inside(self.winding)) {
+                (false, true) => self.start = x,
+                (true, false) => return Some((self.start..x, self.y)),
+                _ => {}
+            }
+        }
+
+        None
+    }
+
+    /// Returns whether the winding number is inside by the fill rule.
+    #[inline]
+    fn inside(&self, winding: i128) -> bool {
+        match self.rule {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+/// Returns the crossings of the row `y` by the edges with their directions.
+///
+/// The vertices of the edges are given in units of `1 / scale` of a pixel. The pixels
+/// from a crossing onwards have their centers right of it or on it.
+#[inline]
+fn crossings<I>(edges: I, scale: i128, y: i128) -> impl Iterator<Item = (i128, i128)>
+where
+    I: Iterator<Item = ((i128, i128), (i128, i128))>,
+{
+    let center = scale * (2 * y + 1);
+    edges.filter_map(move |((x0, y0), (x1, y1))| {
+        if center < 2 * y0.min(y1) || center >= 2 * y0.max(y1) {
+            return None;
+        }
+
+        // The crossing at the center row `y + 1/2`, less a half pixel, as a fraction.
+        let dy = y1 - y0;
+        let num = (2 * x0 - scale) * dy + (center - 2 * y0) * (x1 - x0);
+        let (num, den) = if dy > 0 {
+            (num, 2 * scale * dy)
+        } else {
+            (-num, -2 * scale * dy)
+        };
+        let x = -(-num).div_euclid(den);
+
+        Some((x, dy.signum()))
+    })
+}
+
+/// Returns the first crossing of the row `y` by the edges at `x` greater than `after`,
+/// with the sum of the directions of the edges that cross there.
+#[inline]
+fn first_crossing<I>(edges: I, scale: i128, y: i128, after: Option<i128>) -> Option<(i128, i128)>
+where
+    I: Iterator<Item = ((i128, i128), (i128, i128))>,
+{
+    crossings(edges, scale, y)
+        .filter(|&(x, _)| after.map_or(true, |after| x > after))
+        .fold(None, |first: Option<(i128, i128)>, (x, dir)| match first {
+            Some((min, sum)) if min == x => Some((min, sum + dir)),
+            Some((min, _)) if min < x => first,
+            _ => Some((x, dir)),
+        })
+}
+
+impl<'a, T> PolygonFilled<'a, T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `PolygonFilled` iterator that generates spans of pixel coordinates.
+    ///
+    /// The polygon may be concave or self-intersecting, the last vertex is connected to the first one.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the coordinates of a vertex exceed `2^60`.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The vertices of the polygon.
+    /// * `rule` - The rule that selects the inside of the polygon.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{FillRule, PolygonFilled};
+    ///
+    /// let points = [(0, 0), (4, 0), (0, 4)];
+    /// let vec = PolygonFilled::<i32>::new(&points, FillRule::NonZero).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..3, 0), (0..2, 1), (0..1, 2)]);
+    /// ```
+    #[inline]
+    pub fn new(points: &'a [(T, T)], rule: FillRule) -> Self {
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let limit = 1_i128 << 60;
+        if points
+            .iter()
+            .any(|p| wide(p.0).abs() > limit || wide(p.1).abs() > limit)
+        {
+            panic!("The polygon is too large");
+        }
+
+        let ys = points.iter().map(|p| wide(p.1));
+
+        Self {
+            points,
+            scan: Scanline {
+                rule,
+                y: ys.clone().min().unwrap_or_default(),
+                end_y: ys.max().unwrap_or_default(),
+                ..Scanline::default()
+            },
+        }
+    }
+}
+
+impl<'a, T> Iterator for PolygonFilled<'a, T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        let points = self.points;
+        let n = points.len();
+        let edges = move || {
+            (0..n).map(move |i| {
+                let (a, b) = (points[i], points[(i + 1) % n]);
+                ((wide(a.0), wide(a.1)), (wide(b.0), wide(b.1)))
+            })
+        };
+        let (span, y) = self
+            .scan
+            .next(|y, after| first_crossing(edges(), 1, y, after))?;
+
+        Some((narrow(span.start)..narrow(span.end), narrow(y)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rows = (self.scan.end_y - self.scan.y).max(0).to_usize();
+
+        (
+            0,
+            rows.and_then(|rows| rows.checked_mul(self.points.len() / 2)),
+        )
+    }
+}
+
+impl<'a, T> FusedIterator for PolygonFilled<'a, T> where PolygonFilled<'a, T>: Iterator {}
+
+/// An iterator of the spans of a filled quadratic or cubic Bézier curve, yielded row by row from top to bottom.
+///
+/// The curve is closed by the chord from its end to its start and filled like a [`PolygonFilled`]
+/// of the flattened curve.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct BezierFilled<T> {
+    curve: Flattening,
+    scan: Scanline,
+    coordinate: PhantomData<T>,
+}
+
+impl<T> BezierFilled<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `BezierFilled` iterator of a quadratic curve that generates spans of pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the coordinates of a control point exceed `2^32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first point of the curve.
+    /// * `control` - The control point.
+    /// * `end` - The last point of the curve.
+    /// * `rule` - The rule that selects the inside of the closed curve.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{BezierFilled, FillRule};
+    ///
+    /// let iter = BezierFilled::<i32>::new_quadratic((0, 0), (3, 6), (6, 0), FillRule::NonZero);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..6, 0), (1..5, 1), (2..4, 2)]);
+    /// ```
+    #[inline]
+    pub fn new_quadratic(start: (T, T), control: (T, T), end: (T, T), rule: FillRule) -> Self {
+        Self::with_curve(Flattening::quadratic(start, control, end), rule)
+    }
+
+    /// Creates a new `BezierFilled` iterator of a cubic curve that generates spans of pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the coordinates of a control point exceed `2^32`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first point of the curve.
+    /// * `first` - The control point next to the start.
+    /// * `second` - The control point next to the end.
+    /// * `end` - The last point of the curve.
+    /// * `rule` - The rule that selects the inside of the closed curve.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{BezierFilled, FillRule, Rasterization};
+    ///
+    /// let iter = BezierFilled::<i32>::new_cubic((0, 0), (-4, 8), (12, 8), (8, 0), FillRule::EvenOdd);
+    /// assert_eq!(iter.pixels().count(), 51);
+    /// ```
+    #[inline]
+    pub fn new_cubic(
+        start: (T, T),
+        first: (T, T),
+        second: (T, T),
+        end: (T, T),
+        rule: FillRule,
+    ) -> Self {
+        Self::with_curve(Flattening::cubic(start, first, second, end), rule)
+    }
+
+    #[inline]
+    fn with_curve(curve: Flattening, rule: FillRule) -> Self {
+        let ys = (0..=curve.steps).map(|i| curve.vertex(i).1);
+        let scan = Scanline {
+            rule,
+            y: ys.clone().min().unwrap_or_default(),
+            end_y: ys.max().unwrap_or_default(),
+            ..Scanline::default()
+        };
+
+        Self {
+            curve,
+            scan,
+            coordinate: PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for BezierFilled<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        let curve = &self.curve;
+        let (span, y) = self
+            .scan
+            .next(|y, after| first_crossing(curve.edges(), 1, y, after))?;
+
+        Some((narrow(span.start)..narrow(span.end), narrow(y)))
+    }
+}
+
+impl<T> FusedIterator for BezierFilled<T> where BezierFilled<T>: Iterator {}
+
+/// An iterator of the spans of a filled regular polygon or star, yielded row by row from top to bottom.
+///
+/// The corners lie just inside the circle of the radius around the origin, so the shape is inscribed
+/// in the [`SemicircleFilled`] disc with the same radius.
+/// The first corner points up unless the shape is rotated, the pixels are filled like those of [`PolygonFilled`].
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct RegularPolygon<T> {
+    radius: i128,
+    inner_radius: i128,
+    corners: usize,
+    rotation: f64,
+    scan: Scanline,
+    coordinate: PhantomData<T>,
+}
+
+impl<T> RegularPolygon<T>
+where
+    T: PrimInt + Signed,
+{
+    /// The fraction of a pixel of the coordinates of the corners.
+    const SCALE: i128 = 1 << 20;
+
+    /// The largest rotation in either direction, the reduction of larger angles loses the precision.
+    const MAX_ROTATION: f64 = (1 << 20) as f64;
+
+    /// The distance of the corners inside the circle, the pixel centers left out of
+    /// the [`SemicircleFilled`] disc come within `(2 - √2) / 4` of a pixel of the circle.
+    const MARGIN: i128 = Self::SCALE * 3 / 20;
+
+    /// Creates a new `RegularPolygon` iterator that generates spans of pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the radius cannot be converted to type `T`,
+    ///
+    /// if the radius exceeds `2^36`, if there are less than three sides or
+    ///
+    /// if the rotation is not finite or exceeds `2^20` radians in either direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - A non-negative integer representing the radius of the circumscribed circle.
+    /// * `sides` - The number of sides of the polygon.
+    /// * `rotation` - The clockwise rotation of the polygon in radians.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::RegularPolygon;
+    ///
+    /// let vec = RegularPolygon::<i32>::new(4_u32, 4, 0.0).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-1..1, -3), (-2..2, -2), (-3..3, -1), (-3..3, 0), (-2..2, 1), (-1..1, 2)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(radius: U, sides: usize, rotation: f64) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U>,
+    {
+        if sides < 3 {
+            panic!("A polygon needs at least three sides");
+        }
+
+        let radius = Self::wide_radius(radius);
+        Self::with_corners(radius, radius, sides, rotation)
+    }
+
+    /// Creates a new `RegularPolygon` iterator of a star that generates spans of pixel coordinates.
+    ///
+    /// The corners alternate between the outer radius and the inner radius, beginning with a tip.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a radius cannot be converted to type `T`,
+    ///
+    /// if a radius exceeds `2^36`, if there are less than two tips or
+    ///
+    /// if the rotation is not finite or exceeds `2^20` radians in either direction.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - A non-negative integer representing the radius of the tips.
+    /// * `inner_radius` - A non-negative integer representing the radius of the notches.
+    /// * `tips` - The number of tips of the star.
+    /// * `rotation` - The clockwise rotation of the star in radians.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, RegularPolygon};
+    ///
+    /// let iter = RegularPolygon::<i32>::new_star(20_u32, 8_u32, 5, 0.0);
+    /// assert_eq!(iter.pixels().count(), 460);
+    /// ```
+    #[inline]
+    pub fn new_star<U>(radius: U, inner_radius: U, tips: usize, rotation: f64) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U>,
+    {
+        if tips < 2 {
+            panic!("A star needs at least two tips");
+        }
+
+        Self::with_corners(
+            Self::wide_radius(radius),
+            Self::wide_radius(inner_radius),
+            2 * tips,
+            rotation,
+        )
+    }
+
+    #[inline]
+    fn wide_radius<U>(radius: U) -> i128
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U>,
+    {
+        let wide = <T as TryFrom<U>>::try_from(radius)
+            .unwrap_or_else(|_| {
+                panic!(
+                    "{}",
+                    format_args!("Not possible to convert radius to {}", radius)
+                )
+            })
+            .to_i128()
+            .unwrap_or_default();
+        if wide > 1 << 36 {
+            panic!("Radius is too large");
+        }
+
+        wide
+    }
+
+    #[inline]
+    fn with_corners(radius: i128, inner_radius: i128, corners: usize, rotation: f64) -> Self {
+        if rotation.is_nan() || rotation.abs() > Self::MAX_ROTATION {
+            panic!("The rotation is not finite or too large");
+        }
+
+        let mut polygon = Self {
+            radius,
+            inner_radius,
+            corners,
+            rotation,
+            scan: Scanline::default(),
+            coordinate: PhantomData,
+        };
+        let ys = (0..corners).map(|i| polygon.corner(i).1);
+        let (min, max) = (
+            ys.clone().min().unwrap_or_default(),
+            ys.max().unwrap_or_default(),
+        );
+        polygon.scan = Scanline {
+            rule: FillRule::NonZero,
+            y: min.div_euclid(Self::SCALE),
+            end_y: -(-max).div_euclid(Self::SCALE),
+            ..Scanline::default()
+        };
+
+        polygon
+    }
+
+    /// Returns the corner `i` in units of `1 / SCALE` of a pixel, rounded towards the origin.
+    ///
+    /// The length of the corner is clamped to `MARGIN` inside the circle of the radius.
+    #[inline]
+    fn corner(&self, i: usize) -> (i128, i128) {
+        let radius = match i % 2 {
+            1 if self.corners % 2 == 0 => self.inner_radius,
+            _ => self.radius,
+        };
+        let limit = (self.radius * Self::SCALE - Self::MARGIN).max(0);
+        let length = (radius * Self::SCALE).min(limit);
+        let angle = self.rotation - core::f64::consts::FRAC_PI_2
+            + 2.0 * core::f64::consts::PI * i as f64 / self.corners as f64;
+        let (sin, cos) = sin_cos(angle);
+        let (mut x, mut y) = ((length as f64 * cos) as i128, (length as f64 * sin) as i128);
+        while x * x + y * y > limit * limit {
+            (x, y) = (x - x.signum(), y - y.signum());
+        }
+
+        (x, y)
+    }
+
+    /// Returns the edges between the corners.
+    #[inline]
+    fn edges(&self) -> impl Iterator<Item = ((i128, i128), (i128, i128))> + '_ {
+        (0..self.corners).map(|i| (self.corner(i), self.corner((i + 1) % self.corners)))
+    }
+
+    /// Returns whether the center of the pixel lies inside the shape.
+    #[inline]
+    fn contains(&self, x: i128, y: i128) -> bool {
+        crossings(self.edges(), Self::SCALE, y)
+            .filter(|&(crossing, _)| crossing <= x)
+            .map(|(_, dir)| dir)
+            .sum::<i128>()
+            != 0
+    }
+
+    /// Returns an iterator of the pixels of the outline, the pixels of the shape next to its outside.
+    ///
+    /// The pixels are yielded row by row like the spans, so the outline lies within the filled shape.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::RegularPolygon;
+    ///
+    /// let vec = RegularPolygon::<i32>::new(3_u32, 4, 0.0).outline().collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-1, -2), (0, -2), (-2, -1), (1, -1), (-2, 0), (1, 0), (-1, 1), (0, 1)]);
+    /// ```
+    #[inline]
+    pub fn outline(&self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        T: Debug,
+    {
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        let polygon = self.clone();
+        self.clone().flat_map(move |(range, y)| {
+            let (polygon, y) = (polygon.clone(), wide(y));
+            (wide(range.start)..wide(range.end))
+                .filter(move |&x| {
+                    [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                        .iter()
+                        .any(|&(nx, ny)| !polygon.contains(nx, ny))
+                })
+                .map(move |x| (narrow(x), narrow(y)))
+        })
+    }
+}
+
+impl<T> Iterator for RegularPolygon<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        // The scan is taken out while the corners are borrowed for the crossings.
+        let mut scan = core::mem::take(&mut self.scan);
+        let polygon = &*self;
+        let item = scan.next(|y, after| first_crossing(polygon.edges(), Self::SCALE, y, after));
+        self.scan = scan;
+
+        item.map(|(span, y)| (narrow(span.start)..narrow(span.end), narrow(y)))
+    }
+}
+
+impl<T> FusedIterator for RegularPolygon<T> where RegularPolygon<T>: Iterator {}
+
+/// Returns a non-negative base raised to a positive power.
+#[inline]
+fn powf(base: f64, exponent: f64) -> f64 {
+    use core::f64::consts::LN_2;
+
+    if base <= 0.0 {
+        return 0.0;
+    }
+
+    // The logarithm of the mantissa in `1..2` converges quickly as an area tangent.
+    let bits = base.to_bits();
+    let power = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let (mut ln, mut term) = (0.0, 2.0 * z);
+    for k in 0..30 {
+        ln += term / f64::from(2 * k + 1);
+        term *= z * z;
+    }
+    let x = exponent * (ln + power as f64 * LN_2);
+    if x < -745.0 {
+        return 0.0;
+    }
+
+    // The exponential of the remainder after whole halvings or doublings.
+    let halvings = (x / LN_2) as i64;
+    let r = x - halvings as f64 * LN_2;
+    let (mut exp, mut term) = (0.0, 1.0);
+    for k in 1..30 {
+        exp += term;
+        term *= r / f64::from(k);
+    }
+    let scale = if halvings < 0 { 0.5 } else { 2.0 };
+    (0..halvings.unsigned_abs()).fold(exp, |exp, _| exp * scale)
+}
+
+/// Returns the sine and the cosine of an angle in radians.
+#[inline]
+fn sin_cos(angle: f64) -> (f64, f64) {
+    use core::f64::consts::PI;
+
+    // The angle is reduced to `-PI..=PI`, where the series converge quickly.
+    let turns = angle / (2.0 * PI);
+    let x = angle - 2.0 * PI * (turns - turns % 1.0);
+    let x = match x {
+        x if x > PI => x - 2.0 * PI,
+        x if x < -PI => x + 2.0 * PI,
+        x => x,
+    };
+    let (mut sin, mut cos) = (0.0, 0.0);
+    let mut term = 1.0;
+    for n in 0..40 {
+        match n % 4 {
+            0 => cos += term,
+            1 => sin += term,
+            2 => cos -= term,
+            _ => sin -= term,
+        }
+        term *= x / f64::from(n + 1);
+    }
+
+    (sin, cos)
+}
+
+/// An iterator of the pixels of a filled triangle with the barycentric weights of their centers.
+///
+/// The weights `[w0, w1, w2]` belong to the vertices in the given order and sum to one, so they
+/// interpolate colors, texture coordinates or depth. The pixels are those of [`PolygonFilled`]
+/// with the same vertices, so triangles that share an edge neither overlap nor leave gaps.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Triangle<T> {
+    vertices: [(i128, i128); 3],
+    sign: i128,
+    x: i128,
+    end_x: i128,
+    y: i128,
+    end_y: i128,
+    coordinate: PhantomData<T>,
+}
+
+impl<T> Triangle<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `Triangle` iterator that generates pixel coordinates with weights.
+    ///
+    /// A triangle with collinear vertices has no pixels.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the coordinates of a vertex exceed `2^60`.
+    ///
+    /// # Arguments
+    ///
+    /// * `vertices` - The vertices of the triangle in any orientation.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::Triangle;
+    ///
+    /// let mut iter = Triangle::<i32>::new([(0, 0), (4, 0), (0, 4)]);
+    /// assert_eq!(iter.next(), Some((0, 0, [0.75, 0.125, 0.125])));
+    /// assert_eq!(iter.count(), 5);
+    /// ```
+    #[inline]
+    pub fn new(vertices: [(T, T); 3]) -> Self {
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let limit = 1_i128 << 60;
+        if vertices
+            .iter()
+            .any(|v| wide(v.0).abs() > limit || wide(v.1).abs() > limit)
+        {
+            panic!("The triangle is too large");
+        }
+
+        let vertices = vertices.map(|(x, y)| (2 * wide(x), 2 * wide(y)));
+        let [a, b, c] = vertices;
+        let sign = ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)).signum();
+        let ys = vertices.map(|v| v.1 / 2);
+        let (y, end_y) = match sign {
+            0 => (0, 0),
+            _ => (
+                ys.into_iter().min().unwrap_or_default(),
+                ys.into_iter().max().unwrap_or_default(),
+            ),
+        };
+
+        Self {
+            vertices,
+            sign,
+            x: 0,
+            end_x: 0,
+            y: y - 1,
+            end_y,
+            coordinate: PhantomData,
+        }
+    }
+
+    /// Returns the edge function opposite the vertex `i` at a doubled point, positive inside.
+    #[inline]
+    fn edge(&self, i: usize, (px, py): (i128, i128)) -> i128 {
+        let a = self.vertices[(i + 1) % 3];
+        let b = self.vertices[(i + 2) % 3];
+
+        self.sign * ((b.0 - a.0) * (py - a.1) - (b.1 - a.1) * (px - a.0))
+    }
+
+    /// Returns the span of the pixels of the row `y` inside the triangle.
+    #[inline]
+    fn row(&self, y: i128) -> Range<i128> {
+        (0..3).fold(i128::MIN..i128::MAX, |span, i| {
+            // The edge function along the row is `a * (2 * x + 1) + k`.
+            let a = self.edge(i, (1, 2 * y + 1)) - self.edge(i, (0, 2 * y + 1));
+            let k = self.edge(i, (0, 2 * y + 1));
+            // A center on the edge is inside only if the triangle lies to the right.
+            let least = i128::from(a <= 0);
+            let half = match a.signum() {
+                0 if k >= least => i128::MIN..i128::MAX,
+                0 => 0..0,
+                1 => -(k - least + a).div_euclid(2 * a)..i128::MAX,
+                _ => i128::MIN..(k - least + a).div_euclid(-2 * a) + 1,
+            };
+
+            span.start.max(half.start)..span.end.min(half.end)
+        })
+    }
+}
+
+impl<T> Iterator for Triangle<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (T, T, [f32; 3]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.x >= self.end_x {
+            if self.y + 1 >= self.end_y {
+                return None;
+            }
+
+            self.y += 1;
+            let span = self.row(self.y);
+            (self.x, self.end_x) = (span.start, span.end);
+        }
+
+        let (x, y) = (self.x, self.y);
+        self.x += 1;
+        let center = (2 * x + 1, 2 * y + 1);
+        let edges = [0, 1, 2].map(|i| self.edge(i, center));
+        let area = (edges[0] + edges[1] + edges[2]) as f32;
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+
+        Some((narrow(x), narrow(y), edges.map(|edge| edge as f32 / area)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let row = (self.end_x - self.x).max(0).to_usize().unwrap_or_default();
+
+        (row, None)
+    }
+}
+
+impl<T> FusedIterator for Triangle<T> where Triangle<T>: Iterator {}
+
+/// An iterator of the spans of a line segment with a width, yielded row by row from top to bottom.
+///
+/// The end points are the corners between pixels, like the center of a [`SemicircleFilled`] disc.
+/// The line covers the pixels whose centers lie within half of its width from the segment,
+/// a center on the edge only on one side, so a line along an axis covers as many rows or columns
+/// as its width. The round caps of an even width are the discs of [`SemicircleFilled`], those of
+/// an odd width cover the pixels whose centers lie within half of the width from the end points.
+/// A row is yielded as up to three spans that do not overlap.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ThickLine<T> {
+    origin: (i128, i128),
+    dir: (i128, i128),
+    cross: (i128, i128),
+    dot: (i128, i128),
+    caps: Option<[DiscRows; 2]>,
+    y: i128,
+    end_y: i128,
+    row: [Option<Range<i128>>; 3],
+    row_y: i128,
+    coordinate: PhantomData<T>,
+}
+
+/// The rows of a round cap around a center, taken once each from top to bottom.
+///
+/// An even width takes the rows of a [`SemicircleFilled`] disc, an odd width the pixels whose
+/// centers lie within half of the width.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct DiscRows {
+    center: (i128, i128),
+    width: i128,
+    top: SemicircleFilled<i128>,
+    bottom: SemicircleFilled<i128>,
+}
+
+impl DiscRows {
+    #[inline]
+    fn new(center: (i128, i128), width: i128, disc: &SemicircleFilled<i128>) -> Self {
+        Self {
+            center,
+            width,
+            top: disc.clone(),
+            bottom: disc.clone(),
+        }
+    }
+
+    /// Returns the span of the row `y`, the rows must be asked for in order.
+    #[inline]
+    fn row(&mut self, y: i128) -> Option<Range<i128>> {
+        let rel = y - self.center.1;
+        if self.width % 2 == 1 {
+            // Doubled pixel centers relative to the center, `u^2 + v^2 < width^2`. The sum of
+            // two odd squares is never an odd square, so no center lies on the edge.
+            let v = 2 * rel + 1;
+            let square = self.width * self.width - v * v;
+            let u = (square > 0).then(|| square.sqrt())?;
+            let span = solve_span(1, -u, u, 1 - 2 * self.center.0);
+
+            return (!span.is_empty()).then_some(span);
+        }
+
+        let radius = self.width / 2;
+        let (span, _) = if (-radius..0).contains(&rel) {
+            self.top.next_back()?
+        } else if (0..radius).contains(&rel) {
+            self.bottom.next()?
+        } else {
+            return None;
+        };
+
+        Some(span.start + self.center.0..span.end + self.center.0)
+    }
+}
+
+impl<T> ThickLine<T>
+where
+    T: PrimInt + Signed,
+{
+    /// Creates a new `ThickLine` iterator that generates spans of pixel coordinates.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the width cannot be converted to type `T`,
+    ///
+    /// if the coordinates of the line or its width exceed `2^60`,
+    ///
+    /// if the product of the squared width and the squared length overflows `i128` or
+    ///
+    /// if the pixels within the width from the end points do not fit into type `T`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The first end point of the line.
+    /// * `end` - The second end point of the line.
+    /// * `width` - A non-negative integer representing the width of the line.
+    /// * `cap` - The shape of the ends of the line.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{LineCap, ThickLine};
+    ///
+    /// let vec = ThickLine::<i32>::new((0, 0), (4, 0), 2_u32, LineCap::Butt).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..4, -1), (0..4, 0)]);
+    /// let vec = ThickLine::<i32>::new((0, 0), (4, 0), 2_u32, LineCap::Square).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-1..5, -1), (-1..5, 0)]);
+    /// let vec = ThickLine::<i32>::new((0, 0), (4, 0), 3_u32, LineCap::Butt).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..4, -1), (0..4, 0), (0..4, 1)]);
+    /// let vec = ThickLine::<i32>::new((0, 0), (0, 2), 4_u32, LineCap::Round).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-1..1, -2), (-2..2, -1), (-2..2, 0), (-2..2, 1), (-2..2, 2), (-1..1, 3)]);
+    /// ```
+    #[inline]
+    pub fn new<U>(start: (T, T), end: (T, T), width: U, cap: LineCap) -> Self
+    where
+        U: Unsigned + Display + Copy,
+        T: TryFrom<U>,
+    {
+        let w = <T as TryFrom<U>>::try_from(width)
+            .unwrap_or_else(|_| {
+                panic!(
+                    "{}",
+                    format_args!("Not possible to convert width to {}", width)
+                )
+            })
+            .to_i128()
+            .unwrap_or_default();
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let (start, end) = ((wide(start.0), wide(start.1)), (wide(end.0), wide(end.1)));
+        let limit = 1_i128 << 60;
+        if [start.0, start.1, end.0, end.1, w]
+            .iter()
+            .any(|v| v.abs() > limit)
+        {
+            panic!("The line is too long");
+        }
+
+        let (min, max) = (wide(T::min_value()), wide(T::max_value()));
+        if [start.0, start.1, end.0, end.1]
+            .iter()
+            .any(|v| v - w < min || v + w > max)
+        {
+            panic!("The line does not fit into the coordinate type");
+        }
+
+        // A line of zero length is given a direction, so its square cap is a square.
+        let dir = match (end.0 - start.0, end.1 - start.1) {
+            (0, 0) => (1, 0),
+            dir => dir,
+        };
+        let length = dir.0 * (end.0 - start.0) + dir.1 * (end.1 - start.1);
+        let square = (w * w)
+            .checked_mul(dir.0 * dir.0 + dir.1 * dir.1)
+            .unwrap_or_else(|| panic!("The line is too wide for its length"));
+        let reach = square.sqrt();
+        // A center on the edge is only taken on one side, which can only happen for odd widths.
+        let near = i128::from(reach * reach == square) - reach;
+        let cross = (near, reach);
+        let dot = match cap {
+            LineCap::Square => (near, 2 * length + reach),
+            LineCap::Butt | LineCap::Round => (0, 2 * length),
+        };
+        let caps = (cap == LineCap::Round).then(|| {
+            let disc = SemicircleFilled::<i128>::new((w / 2) as u128);
+            [DiscRows::new(start, w, &disc), DiscRows::new(end, w, &disc)]
+        });
+
+        Self {
+            origin: (2 * start.0, 2 * start.1),
+            dir,
+            cross,
+            dot,
+            caps,
+            y: start.1.min(end.1) - w,
+            end_y: start.1.max(end.1) + w,
+            row: [None, None, None],
+            row_y: 0,
+            coordinate: PhantomData,
+        }
+    }
+
+    /// Returns the span of the row `y` covered by the body of the line.
+    #[inline]
+    fn body(&self, y: i128) -> Option<Range<i128>> {
+        // Doubled pixel centers relative to the start, `u = 2 * x + e`.
+        let v = 2 * y + 1 - self.origin.1;
+        let e = 1 - self.origin.0;
+        let k = self.dir.0 * v;
+        let across = solve_span(self.dir.1, k - self.cross.1, k - self.cross.0, e);
+        let along = solve_span(
+            self.dir.0,
+            self.dot.0 - self.dir.1 * v,
+            self.dot.1 - self.dir.1 * v,
+            e,
+        );
+        let span = across.start.max(along.start)..across.end.min(along.end);
+
+        (!span.is_empty()).then_some(span)
+    }
+}
+
+/// Returns the span of `x` with `lo <= a * (2 * x + e) <= hi`.
+#[inline]
+fn solve_span(a: i128, lo: i128, hi: i128, e: i128) -> Range<i128> {
+    let (a, lo, hi) = match a.signum() {
+        0 if lo <= 0 && hi >= 0 => return i128::MIN..i128::MAX,
+        0 => return 0..0,
+        1 => (a, lo, hi),
+        _ => (-a, -hi, -lo),
+    };
+    let floor = |n: i128| n.div_euclid(2 * a);
+
+    -floor(a * e - lo)..floor(hi - a * e) + 1
+}
+
+impl<T> Iterator for ThickLine<T>
+where
+    T: PrimInt + Signed,
+{
+    type Item = (Range<T>, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        loop {
+            if let Some(span) = self.row.iter_mut().find_map(Option::take) {
+                return Some((narrow(span.start)..narrow(span.end), narrow(self.row_y)));
+            }
+
+            if self.y >= self.end_y {
+                return None;
+            }
+
+            let y = self.y;
+            self.y += 1;
+            let [first, second] = match &mut self.caps {
+                Some([start, end]) => [start.row(y), end.row(y)],
+                None => [None, None],
+            };
+            let mut spans = [self.body(y), first, second];
+            spans.sort_unstable_by_key(|span| span.as_ref().map(|span| span.start));
+            // Joins the spans that overlap or touch.
+            let mut row: [Option<Range<i128>>; 3] = [None, None, None];
+            let mut last = 0;
+            for span in spans.into_iter().flatten() {
+                match &mut row[last] {
+                    Some(joined) if span.start <= joined.end => {
+                        joined.end = joined.end.max(span.end)
+                    }
+                    Some(_) => {
+                        last += 1;
+                        row[last] = Some(span);
+                    }
+                    None => row[last] = Some(span),
+                }
+            }
+
+            self.row = row;
+            self.row_y = y;
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.row.iter().flatten().count();
+        let rows = (self.end_y - self.y).max(0).to_usize();
+
+        (
+            pending,
+            rows.and_then(|rows| rows.checked_mul(3))
+                .and_then(|spans| spans.checked_add(pending)),
+        )
+    }
+}
+
+impl<T> FusedIterator for ThickLine<T> where ThickLine<T>: Iterator {}
+
+/// Moves the top rows by `start` and the mirrored rows by `end` along the y axis,
+/// fills the rows between them with the first row and limits each span to `clip`.
+///
+/// Each row comes with the row it is mirrored to, the center row of a circle
+/// centered on a pixel is not mirrored.
+#[inline]
+fn tall_rows<I, T>(
+    rows: I,
+    start: T,
+    end: T,
+    clip: fn(Range<T>) -> Range<T>,
+) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+where
+    I: Iterator<Item = (Range<T>, T, Option<T>)> + Clone + Debug,
+    T: Add<Output = T> + One + Copy + Debug,
+    Range<T>: Iterator<Item = T>,
+{
+    let fill = rows
+        .clone()
+        .next()
+        .into_iter()
+        .flat_map(move |(range, y, _)| {
+            (y + start + T::one()..y + end + T::one()).map(move |y| (range.clone(), y))
+        });
+    rows.flat_map(move |(range, y, mirror)| {
+        let mirror = mirror.map(|y| (range.clone(), y + end));
+        once((range, y + start)).chain(mirror)
+    })
+    .chain(fill)
+    .map(move |(range, y)| (clip(range), y))
+}
+
+/// Creates (x, y) coordinates for the pixels of each span.
+#[inline]
+fn span_pixels<I, T>(spans: I) -> impl Iterator<Item = (T, T)> + Clone + Debug
+where
+    I: Iterator<Item = (Range<T>, T)> + Clone + Debug,
+    T: Copy,
+    Range<T>: Iterator<Item = T> + Clone,
+{
+    Pixels::new(spans.map(|(range, y)| (range, y, None)))
+}
+
+/// Returns the span of the row in the top half.
+#[inline]
+fn top<T>(row: impl Row<T>) -> (Range<T>, T) {
+    let (span, y, _) = row.into_row();
+    (span, y)
+}
+
+/// Returns the span of the mirrored row in the bottom half, if the row is mirrored.
+#[inline]
+fn bottom<T>(row: impl Row<T>) -> Option<(Range<T>, T)> {
+    let (span, _, mirror) = row.into_row();
+    mirror.map(|y| (span, y))
+}
+
+/// The largest number of remaining rows whose pixels [`Pixels`] counts for its size hint.
+const COUNTED_ROWS: usize = 1 << 16;
+
+/// An iterator adapter that creates (x, y) coordinates for the pixels of each row.
+///
+/// Each row is a span, its row and the row it is mirrored to, if any. The pixel of the mirrored
+/// row follows each pixel of the row.
+#[derive(Clone)]
+struct Pixels<I, R, T> {
+    rows: I,
+    row: Option<(R, T, Option<T>)>,
+    mirror: Option<(T, T)>,
+}
+
+impl<I, R, T> Pixels<I, R, T> {
+    #[inline]
+    fn new(rows: I) -> Self {
+        Self {
+            rows,
+            row: None,
+            mirror: None,
+        }
+    }
+}
+
+impl<I: Debug, R, T> Debug for Pixels<I, R, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pixels")
+            .field("rows", &self.rows)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<I, R, T> Iterator for Pixels<I, R, T>
+where
+    I: Iterator<Item = (R, T, Option<T>)> + Clone,
+    R: Iterator<Item = T>,
+    T: Copy,
+{
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(pixel) = self.mirror.take() {
+            return Some(pixel);
+        }
+
+        loop {
+            if let Some((span, y, mirror)) = &mut self.row {
+                if let Some(x) = span.next() {
+                    self.mirror = mirror.map(|y| (x, y));
+                    return Some((x, *y));
+                }
+            }
+            self.row = Some(self.rows.next()?);
+        }
+    }
+
+    /// Counts the pixels left in the current row and, up to [`COUNTED_ROWS`] rows,
+    /// the pixels of the remaining rows.
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pixels = |(span, _, mirror): &(R, T, Option<T>)| {
+            let copies = 1 + usize::from(mirror.is_some());
+            let (lower, upper) = span.size_hint();
+            (
+                lower.saturating_mul(copies),
+                upper.and_then(|upper| upper.checked_mul(copies)),
+            )
+        };
+        let pending = usize::from(self.mirror.is_some());
+        let (lower, upper) = self.row.as_ref().map_or((0, Some(0)), pixels);
+        let (lower, upper) = (
+            lower.saturating_add(pending),
+            upper.and_then(|upper| upper.checked_add(pending)),
+        );
+
+        match self.rows.size_hint() {
+            (_, Some(rows)) if rows <= COUNTED_ROWS => {
+                self.rows
+                    .clone()
+                    .fold((lower, upper), |(lower, upper), row| {
+                        let (row_lower, row_upper) = pixels(&row);
+                        (
+                            lower.saturating_add(row_lower),
+                            upper.zip(row_upper).and_then(|(a, b)| a.checked_add(b)),
+                        )
+                    })
+            }
+            _ => (lower, None),
+        }
+    }
+}
+
+/// Limits the bounds to the pixels right of the center, the outer end is moved by `end`.
+///
+/// A span that lies entirely left of the center is emptied.
+#[inline]
+fn right_bounds<T>(bounds: Range<T>, end: T) -> Range<T>
+where
+    T: Add<Output = T> + Zero + PartialOrd + Copy,
+{
+    if bounds.start < T::zero() && bounds.end <= T::zero() {
+        return T::zero()..T::zero();
+    }
+
+    let start = if bounds.start > T::zero() {
+        bounds.start
+    } else {
+        T::zero()
+    };
+    start..bounds.end + end
+}
+
+/// Limits the bounds to the pixels left of the center, the inner end is moved by `end`.
+///
+/// A span that lies entirely right of the center is emptied.
+#[inline]
+fn left_bounds<T>(bounds: Range<T>, end: T) -> Range<T>
+where
+    T: Add<Output = T> + Zero + PartialOrd + Copy,
+{
+    if bounds.start >= T::zero() && bounds.end > T::zero() {
+        return T::zero()..T::zero();
+    }
+
+    let stop = if bounds.end < T::zero() {
+        bounds.end
+    } else {
+        T::zero()
+    };
+    bounds.start..stop + end
+}
+
+/// Returns the smallest `d >= 0` with `d² + b·d + c <= 0`, or `i128::MAX` if there is none.
+///
+/// Returns `None` if the discriminant overflows.
+#[inline]
+fn first_within(b: i128, c: i128) -> Option<i128> {
+    if c <= 0 {
+        return Some(0);
+    }
+
+    let discriminant = b.checked_mul(b)?.checked_sub(c.checked_mul(4)?)?;
+    if discriminant < 0 {
+        return Some(i128::MAX);
+    }
+
+    // The rounding of the square root cannot move the root past the next integer.
+    let d = (-(b + discriminant.sqrt()).div_euclid(2)).max(0);
+    let within = d.checked_mul(d.checked_add(b)?)?.checked_add(c)? <= 0;
+
+    Some(if within { d } else { i128::MAX })
+}
+
+/// Returns the smallest `d >= 0` with `d² + b·d + c > 0` for a non-negative `b`.
+///
+/// Returns `None` if the discriminant overflows.
+#[inline]
+fn first_above(b: i128, c: i128) -> Option<i128> {
+    if c > 0 {
+        return Some(0);
+    }
+
+    let discriminant = b.checked_mul(b)?.checked_sub(c.checked_mul(4)?)?;
+
+    Some((discriminant.sqrt() - b) / 2 + 1)
+}
+
+/// Returns the exact product of two integers as the high and the low half of a 256-bit integer.
+///
+/// The halves compare in the same order as the products.
+#[inline]
+fn wide_mul(a: i128, b: i128) -> (i128, u128) {
+    const LOW: u128 = u64::MAX as u128;
+    let (x, y) = (a.unsigned_abs(), b.unsigned_abs());
+    let (x_high, x_low, y_high, y_low) = (x >> 64, x & LOW, y >> 64, y & LOW);
+    let (low, cross_low, cross_high) = (x_low * y_low, x_low * y_high, x_high * y_low);
+    let middle = (low >> 64) + (cross_low & LOW) + (cross_high & LOW);
+    let low = (low & LOW) | (middle << 64);
+    // The magnitude is below `2^254`, so the high half fits into `i128`.
+    let high = (x_high * y_high + (cross_low >> 64) + (cross_high >> 64) + (middle >> 64)) as i128;
+
+    if (a < 0) == (b < 0) {
+        return (high, low);
+    }
+
+    (-high - i128::from(low != 0), low.wrapping_neg())
+}
+
+/// Returns whether the pixel lies in the arc running counterclockwise from `start` to `end`.
+///
+/// The pixel is tested by its center, so the coordinates are doubled to stay in integers.
+/// The products are compared in 256 bits, so no coordinates of type `i128` can overflow.
+/// The arc includes the start direction and excludes the end direction,
+/// equal directions select the full circle.
+#[inline]
+fn in_arc(start: (i128, i128), end: (i128, i128), (x, y): (i128, i128)) -> bool {
+    let cross = |u: (i128, i128), v: (i128, i128)| wide_mul(u.0, v.1).cmp(&wide_mul(u.1, v.0));
+    let dot = |u: (i128, i128), v: (i128, i128)| {
+        let (high, low) = wide_mul(u.1, v.1);
+        wide_mul(u.0, v.0).cmp(&(-high - i128::from(low != 0), low.wrapping_neg()))
+    };
+    // The second half turn from the start direction, the y axis points down.
+    let past_half = |v: (i128, i128)| match cross(v, start) {
+        Ordering::Less => true,
+        Ordering::Equal => dot(v, start).is_lt(),
+        Ordering::Greater => false,
+    };
+
+    if cross(end, start).is_eq() && dot(end, start).is_gt() {
+        return true;
+    }
+
+    let center = (2 * x + 1, 2 * y + 1);
+    match (past_half(center), past_half(end)) {
+        (false, true) => true,
+        (true, false) => false,
+        _ => cross(end, center).is_gt(),
+    }
+}
+
+/// Clips a row to the pixels whose centers lie in the arc from `start` to `end`, see [`in_arc`].
+///
+/// The membership only changes at the pixels where the rays of the arc cross the row,
+/// so the row is cut around these pixels and each part is tested by its first pixel.
+#[inline]
+fn clip_to_arc(
+    span: Range<i128>,
+    y: i128,
+    start: (i128, i128),
+    end: (i128, i128),
+) -> [Option<Range<i128>>; 2] {
+    let center_y = 2 * y + 1;
+    let mut cuts = [span.end; 4];
+    for (cut, ray) in cuts.chunks_exact_mut(2).zip([start, end]) {
+        if ray.1 != 0 && (ray.1 > 0) == (center_y > 0) {
+            // The ray crosses the row at the doubled x of `ray.0 * center_y / ray.1`.
+            let (num, den) = (ray.0 * center_y - ray.1, 2 * ray.1);
+            let x = if den > 0 {
+                num.div_euclid(den)
+            } else {
+                (-num).div_euclid(-den)
+            };
+            cut.copy_from_slice(&[x, x + 1]);
+        }
+    }
+    cuts.sort_unstable();
+
+    let mut parts: [Option<Range<i128>>; 2] = [None, None];
+    let mut count = 0_usize;
+    let mut from = span.start;
+    for cut in cuts.into_iter().chain(once(span.end)) {
+        let cut = cut.clamp(span.start, span.end);
+        if cut <= from {
+            continue;
+        }
+
+        if in_arc(start, end, (from, y)) {
+            match count.checked_sub(1).and_then(|last| parts[last].as_mut()) {
+                Some(part) if part.end == from => part.end = cut,
+                _ => {
+                    parts[count] = Some(from..cut);
+                    count += 1;
+                }
+            }
+        }
+        from = cut;
+    }
+
+    parts
+}
+
+/// A row of a filled circle, a span of x coordinates and the y coordinate.
+///
+/// The kind of span marks how the adapters of [`Rasterization`] mirror the row into the bottom half.
+/// The half-open spans of [`SemicircleFilled`] are mirrored from `y` to `-y - 1`, the inclusive
+/// spans of [`SemicircleCentered`] are mirrored from `y` to `-y` and the center row is not mirrored.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```rust
+/// use rasterization::Row;
+///
+/// assert_eq!((-2..2, -1).into_row(), (-2..2, -1, Some(0)));
+/// assert_eq!((-2..=2, -1).into_row(), (-2..3, -1, Some(1)));
+/// assert_eq!((-2..=2, 0).into_row(), (-2..3, 0, None));
+/// ```
+pub trait Row<T> {
+    /// Returns the span as a half-open range, the row and the row it is mirrored to, if any.
+    fn into_row(self) -> (Range<T>, T, Option<T>);
+}
+
+impl<T> Row<T> for (Range<T>, T)
+where
+    T: One + Sub<Output = T> + Neg<Output = T> + Copy,
+{
+    #[inline]
+    fn into_row(self) -> (Range<T>, T, Option<T>) {
+        let (span, y) = self;
+        (span, y, Some(-y - T::one()))
+    }
+}
+
+impl<T> Row<T> for (RangeInclusive<T>, T)
+where
+    T: Add<Output = T> + One + Zero + Neg<Output = T> + PartialOrd + Copy,
+{
+    #[inline]
+    fn into_row(self) -> (Range<T>, T, Option<T>) {
+        let (span, y) = self;
+        let span = if span.is_empty() {
+            *span.start()..*span.start()
+        } else {
+            *span.start()..*span.end() + T::one()
+        };
+        (span, y, (!y.is_zero()).then(|| -y))
+    }
+}
+
+/// The trait for rasterization of given figures.
+pub trait Rasterization: Iterator {
+    /// An iterator adapter that creates (x, y) coordinates for the filled full circle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_usize).circle();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2, -1), (-2, 0), (-1, -1), (-1, 0), (0, -1), (0, 0),
+    ///           (1, -1), (1, 0), (-1, -2), (-1, 1), (0, -2), (0, 1)]);
+    /// ```
+    #[inline]
+    fn circle<T, R>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator<Item = (R, T)> + Clone + Debug,
+        (R, T): Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
+        R: Iterator<Item = T> + Clone + Debug,
+        Range<T>: Iterator<Item = T> + Clone,
+    {
+        Pixels::new(self.map(|row| row.into_row()))
+    }
+
+    /// The iterator adapter adds an offset to a two-element tuple.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    ///
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let radius = 2_usize;
+    /// let offset_x = radius as i32;
+    /// let offset_y = radius as i32;
+    /// let iter = SemicircleFilled::<i32>::new(radius).circle().offset(offset_x, offset_y);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 1), (0, 2), (1, 1), (1, 2), (2, 1), (2, 2),
+    ///           (3, 1), (3, 2), (1, 0), (1, 3), (2, 0), (2, 3)]);
+    /// ```
+    #[inline]
+    fn offset<T>(self, offset_x: T, offset_y: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator<Item = (T, T)> + Clone + Debug,
+        T: Add<Output = T> + Copy + Debug,
+    {
+        self.map(move |(x, y)| (x + offset_x, y + offset_y))
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the pixels of each span.
+    ///
+    /// The rows are taken as they are, so this fills the shapes that yield all of their rows,
+    /// like [`RoundedRect`], [`Rectangle`] and [`PolygonFilled`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, Rectangle};
+    ///
+    /// let iter = Rectangle::<i32>::new(3_u32, 2_u32).pixels();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+    /// ```
+    #[inline]
+    fn pixels<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
+        T: Copy,
+        Range<T>: Iterator<Item = T> + Clone,
+    {
+        span_pixels(self)
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled long circle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_u64).circle_long(1, -1);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-1, -1), (-1, 0), (0, -1), (0, 0)]);
+    /// ```
+    #[inline]
+    fn circle_long<T>(self, start: T, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign + Debug,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
+    {
+        Pixels::new(self.map(move |row| {
+            let (mut span, y, mirror) = row.into_row();
+            span.start += start;
+            span.end += end;
+            (span, y, mirror)
+        }))
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled top semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_u8).semicircle_top();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2, -1), (-1, -1), (0, -1), (1, -1), (-1, -2), (0, -2)]);
+    /// ```
+    #[inline]
+    fn semicircle_top<T, R>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator<Item = (R, T)> + Clone + Debug,
+        (R, T): Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy,
+        R: Iterator<Item = T> + Clone + Debug,
+        Range<T>: Iterator<Item = T> + Clone,
+    {
+        span_pixels(self.semicircle_top_spans())
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled bottom semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_u16).semicircle_bottom();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2, 0), (-1, 0), (0, 0), (1, 0), (-1, 1), (0, 1)]);
+    /// ```
+    #[inline]
+    fn semicircle_bottom<T, R>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator<Item = (R, T)> + Clone + Debug,
+        (R, T): Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy,
+        R: Iterator<Item = T> + Clone + Debug,
+        Range<T>: Iterator<Item = T> + Clone,
+    {
+        span_pixels(self.semicircle_bottom_spans())
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled top long semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_u32).semicircle_top_long(0, 1);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2, -1), (-1, -1), (0, -1), (1, -1), (2, -1), (-1, -2), (0, -2), (1, -2)]);
+    /// ```
+    #[inline]
+    fn semicircle_top_long<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
+    {
+        span_pixels(self.semicircle_top_long_spans(start, end))
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled bottom long semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_usize).semicircle_bottom_long(0, 1);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2, 0), (-1, 0), (0, 0), (1, 0), (2, 0), (-1, 1), (0, 1), (1, 1)]);
+    /// ```
+    #[inline]
+    fn semicircle_bottom_long<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
+    {
+        span_pixels(self.semicircle_bottom_long_spans(start, end))
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled tall circle.
+    ///
+    /// The top half is moved by `start` and the bottom half by `end` along the y axis,
+    /// the rows between the halves are filled with the full width of the circle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(1_u32).circle_tall(0, 1);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-1, -1), (0, -1), (-1, 1), (0, 1), (-1, 0), (0, 0)]);
+    /// ```
+    #[inline]
+    fn circle_tall<T>(self, start: T, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Zero + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
+        Range<T>: Iterator<Item = T>,
+    {
+        span_pixels(self.circle_tall_spans(start, end))
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled left tall semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_u32).semicircle_left_tall(-1, 0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2, -2), (-1, -2), (-2, 0), (-1, 0), (-1, -3), (-1, 1), (-2, -1), (-1, -1)]);
+    /// ```
+    #[inline]
+    fn semicircle_left_tall<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + One
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + Debug,
+        Range<T>: Iterator<Item = T>,
+    {
+        span_pixels(self.semicircle_left_tall_spans(start, end))
+    }
+
+    /// An iterator adapter that creates (x, y) coordinates for the filled right tall semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_u32).semicircle_right_tall(-1, 0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, -2), (1, -2), (0, 0), (1, 0), (0, -3), (0, 1), (0, -1), (1, -1)]);
+    /// ```
+    #[inline]
+    fn semicircle_right_tall<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + One
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + Debug,
+        Range<T>: Iterator<Item = T>,
+    {
+        span_pixels(self.semicircle_right_tall_spans(start, end))
+    }
+
+    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the first quadrant.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(3_u64).first_quadrant(0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, -1), (1, -1), (2, -1), (0, -2), (1, -2), (2, -2), (0, -3), (1, -3)]);
+    /// ```
+    #[inline]
+    fn first_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + AddAssign,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
+    {
+        span_pixels(self.first_quadrant_spans(end))
+    }
+
+    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the second quadrant.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i64>::new(3_u8).second_quadrant(0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-3, -1), (-2, -1), (-1, -1), (-3, -2), (-2, -2), (-1, -2), (-2, -3), (-1, -3)]);
+    /// ```
+    #[inline]
+    fn second_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + AddAssign,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
+    {
+        span_pixels(self.second_quadrant_spans(end))
+    }
+
+    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the third quadrant.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i64>::new(3_u16).third_quadrant(0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-3, 0), (-2, 0), (-1, 0), (-3, 1), (-2, 1), (-1, 1), (-2, 2), (-1, 2)]);
+    /// ```
+    #[inline]
+    fn third_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + One
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + AddAssign,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
+    {
+        span_pixels(self.third_quadrant_spans(end))
+    }
+
+    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the fourth quadrant.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i64>::new(3_u32).fourth_quadrant(0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1), (0, 2), (1, 2)]);
+    /// ```
+    #[inline]
+    fn fourth_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + One
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + AddAssign,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
+    {
+        span_pixels(self.fourth_quadrant_spans(end))
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled full circle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_usize).circle_spans();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..2, -1), (-2..2, 0), (-1..1, -2), (-1..1, 1)]);
+    /// ```
+    #[inline]
+    fn circle_spans<T>(self) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: One + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
+    {
+        self.flat_map(|row| {
+            let (span, y, mirror) = row.into_row();
+            once((span.clone(), y)).chain(mirror.map(|y| (span, y)))
+        })
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled long circle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_u64).circle_long_spans(-1, 1);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-3..3, -1), (-3..3, 0), (-2..2, -2), (-2..2, 1)]);
+    /// ```
+    #[inline]
+    fn circle_long_spans<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
+    {
+        self.flat_map(move |row| {
+            let (span, y, mirror) = row.into_row();
+            let span = span.start + start..span.end + end;
+            once((span.clone(), y)).chain(mirror.map(|y| (span, y)))
+        })
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled top semicircle.
+    ///
+    /// The inclusive spans of [`SemicircleCentered`] become half-open spans.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_u8).semicircle_top_spans();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..2, -1), (-1..1, -2)]);
+    /// ```
+    #[inline]
+    fn semicircle_top_spans<T>(self) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+    {
+        self.map(top)
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled bottom semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_u16).semicircle_bottom_spans();
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..2, 0), (-1..1, 1)]);
+    /// ```
+    #[inline]
+    fn semicircle_bottom_spans<T>(self) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: One + Sub<Output = T> + Neg<Output = T> + Copy,
+    {
+        self.filter_map(bottom)
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled top long semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_u32).semicircle_top_long_spans(0, 1);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..3, -1), (-1..2, -2)]);
+    /// ```
+    #[inline]
+    fn semicircle_top_long_spans<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + Copy,
+    {
+        self.map(move |row| {
+            let (span, y) = top(row);
+            (span.start + start..span.end + end, y)
+        })
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled bottom long semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(2_usize).semicircle_bottom_long_spans(0, 1);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..3, 0), (-1..2, 1)]);
+    /// ```
+    #[inline]
+    fn semicircle_bottom_long_spans<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy,
+    {
+        self.filter_map(move |row| {
+            bottom(row).map(|(span, y)| (span.start + start..span.end + end, y))
+        })
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled tall circle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_u32).circle_tall_spans(-1, 0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..2, -2), (-2..2, 0), (-1..1, -3), (-1..1, 1), (-2..2, -1)]);
+    /// ```
+    #[inline]
+    fn circle_tall_spans<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Zero + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
+        Range<T>: Iterator<Item = T>,
+    {
+        let rows = self.map(|row| row.into_row());
+        tall_rows(rows, start, end, |range| range)
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled left tall semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_u32).semicircle_left_tall_spans(-1, 0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-2..0, -2), (-2..0, 0), (-1..0, -3), (-1..0, 1), (-2..0, -1)]);
+    /// ```
+    #[inline]
+    fn semicircle_left_tall_spans<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + One
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + Debug,
+        Range<T>: Iterator<Item = T>,
+    {
+        let rows = self.map(|row| row.into_row());
+        tall_rows(rows, start, end, |range| left_bounds(range, T::zero()))
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled right tall semicircle.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<i32>::new(2_u32).semicircle_right_tall_spans(-1, 0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..2, -2), (0..2, 0), (0..1, -3), (0..1, 1), (0..2, -1)]);
+    /// ```
+    #[inline]
+    fn semicircle_right_tall_spans<T>(
+        self,
+        start: T,
+        end: T,
+    ) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T>
+            + One
+            + Zero
+            + Sub<Output = T>
+            + Neg<Output = T>
+            + PartialOrd
+            + Copy
+            + Debug,
+        Range<T>: Iterator<Item = T>,
+    {
+        let rows = self.map(|row| row.into_row());
+        tall_rows(rows, start, end, |range| right_bounds(range, T::zero()))
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled circle of the first quadrant.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
+    ///
+    /// let iter = SemicircleFilled::<isize>::new(3_u64).first_quadrant_spans(0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0..3, -1), (0..3, -2), (0..2, -3)]);
+    /// ```
+    #[inline]
+    fn first_quadrant_spans<T>(self, end: T) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
+    where
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + Zero + PartialOrd + Copy,
+        Range<T>: Iterator<Item = T>,
+    {
+        self.map(move |row| {
+            let (span, y) = top(row);
+            (right_bounds(span, end), y)
+        })
+    }
+
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled circle of the second quadrant.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, SemicircleFilled};
     ///
-    /// let radius = 5_u32;
-    /// let semicircle_iter = SemicircleFilled::<i32>::new(radius);
-    /// let vec = semicircle_iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-5..5, -1), (-5..5, -2), (-4..4, -3), (-3..3, -4), (-2..2, -5)]);
+    /// let iter = SemicircleFilled::<i64>::new(3_u8).second_quadrant_spans(0);
+    /// let vec = iter.collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(-3..0, -1), (-3..0, -2), (-2..0, -3)]);
     /// ```
     #[inline]
-    pub fn new<U>(radius: U) -> Self
+    fn second_quadrant_spans<T>(self, end: T) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
     where
-        U: Unsigned + Display + Copy,
-        T: From<i8>
-            + TryFrom<U>
-            + Sub<Output = T>
-            + Neg<Output = T>
-            + Default
-            + Mul<Output = T>
-            + FromAs<isize>
-            + PartialOrd
-            + Copy,
-        <T as TryFrom<U>>::Error: Debug,
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + Zero + PartialOrd + Copy,
+        Range<T>: Iterator<Item = T>,
     {
-        let Ok(r) = <T as TryFrom<U>>::try_from(radius) else {
-            panic!(
-                "{}",
-                format_args!("Not possible to convert radius to {}", radius)
-            )
-        };
-        let m_radius = <T as FromAs<isize>>::from_as(100_000_000_isize);
-        if r < m_radius {
-            return Self {
-                x: -r,
-                y: 0.into(),
-                err: <T as From<i8>>::from(2) - <T as From<i8>>::from(2) * r,
-            };
-        }
-
-        panic!("Radius is too large")
-    }
-}
-
-impl Iterator for SemicircleFilled<i32> {
-    type Item = (Range<i32>, i32);
-
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.x == 0 {
-            return None;
-        }
-
-        let xy = (self.x..-self.x, -(self.y + 1));
-
-        // FIXME: This loop creates redundant iterations!
-        loop {
-            let y_tmp = self.y;
-            let err_tmp = self.err;
-
-            if err_tmp <= self.y {
-                self.y += 1;
-                self.err += 2 * self.y + 2;
-            };
-
-            if err_tmp > self.x || self.err > self.y {
-                self.x += 1;
-                self.err += 2 * self.x + 2;
-            }
-
-            if y_tmp != self.y || self.x == 0 {
-                break;
-            }
-        }
-
-        Some(xy)
-    }
-}
-
-impl Iterator for SemicircleFilled<isize> {
-    type Item = (Range<isize>, isize);
-
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.x == 0 {
-            return None;
-        }
-
-        let xy = (self.x..-self.x, -(self.y + 1));
-
-        // FIXME: This loop creates redundant iterations!
-        loop {
-            let y_tmp = self.y;
-            let err_tmp = self.err;
-
-            if err_tmp <= self.y {
-                self.y += 1;
-                self.err += 2 * self.y + 2;
-            };
-
-            if err_tmp > self.x || self.err > self.y {
-                self.x += 1;
-                self.err += 2 * self.x + 2;
-            }
-
-            if y_tmp != self.y || self.x == 0 {
-                break;
-            }
-        }
-
-        Some(xy)
-    }
-}
-
-impl Iterator for SemicircleFilled<i64> {
-    type Item = (Range<i64>, i64);
-
-    #[inline]
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.x == 0 {
-            return None;
-        }
-
-        let xy = (self.x..-self.x, -(self.y + 1));
-
-        // FIXME: This loop creates redundant iterations!
-        loop {
-            let y_tmp = self.y;
-            let err_tmp = self.err;
-
-            if err_tmp <= self.y {
-                self.y += 1;
-                self.err += 2 * self.y + 2;
-            };
-
-            if err_tmp > self.x || self.err > self.y {
-                self.x += 1;
-                self.err += 2 * self.x + 2;
-            }
-
-            if y_tmp != self.y || self.x == 0 {
-                break;
-            }
-        }
-
-        Some(xy)
+        self.map(move |row| {
+            let (span, y) = top(row);
+            (left_bounds(span, end), y)
+        })
     }
-}
-
-impl<T> FusedIterator for SemicircleFilled<T> where SemicircleFilled<T>: Iterator {}
 
-/// The trait for rasterization of given figures.
-pub trait Rasterization: Iterator {
-    /// An iterator adapter that creates (x, y) coordinates for the filled full circle.
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled circle of the third quadrant.
     ///
     /// # Examples
     ///
@@ -230,49 +4322,53 @@ pub trait Rasterization: Iterator {
     /// ```rust
     /// use rasterization::{Rasterization, SemicircleFilled};
     ///
-    /// let iter = SemicircleFilled::<i32>::new(2_usize).circle();
+    /// let iter = SemicircleFilled::<i64>::new(3_u16).third_quadrant_spans(0);
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-2, -1), (-2, 0), (-1, -1), (-1, 0), (0, -1), (0, 0),
-    ///           (1, -1), (1, 0), (-1, -2), (-1, 1), (0, -2), (0, 1)]);
+    /// assert_eq!(vec, vec![(-3..0, 0), (-3..0, 1), (-2..0, 2)]);
     /// ```
     #[inline]
-    fn circle<T, R>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn third_quadrant_spans<T>(self, end: T) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
     where
-        Self: Sized + Iterator<Item = (R, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
-        R: Iterator<Item = T> + Clone + Debug,
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Zero + Sub<Output = T> + Neg<Output = T> + PartialOrd + Copy,
+        Range<T>: Iterator<Item = T>,
     {
-        self.flat_map(|(range, y)| range.flat_map(move |x| [(x, y), (x, -y - T::one())]))
+        self.filter_map(move |row| bottom(row).map(|(span, y)| (left_bounds(span, end), y)))
     }
 
-    /// The iterator adapter adds an offset to a two-element tuple.
+    /// An iterator adapter that creates a span of x coordinates for each row of the filled circle of the fourth quadrant.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    ///
     /// use rasterization::{Rasterization, SemicircleFilled};
     ///
-    /// let radius = 2_usize;
-    /// let offset_x = radius as i32;
-    /// let offset_y = radius as i32;
-    /// let iter = SemicircleFilled::<i32>::new(radius).circle().offset(offset_x, offset_y);
+    /// let iter = SemicircleFilled::<i64>::new(3_u32).fourth_quadrant_spans(0);
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(0, 1), (0, 2), (1, 1), (1, 2), (2, 1), (2, 2),
-    ///           (3, 1), (3, 2), (1, 0), (1, 3), (2, 0), (2, 3)]);
+    /// assert_eq!(vec, vec![(0..3, 0), (0..3, 1), (0..2, 2)]);
     /// ```
     #[inline]
-    fn offset<T>(self, offset_x: T, offset_y: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn fourth_quadrant_spans<T>(self, end: T) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
     where
-        Self: Sized + Iterator<Item = (T, T)> + Clone + Debug,
-        T: Add<Output = T> + Copy + Debug,
+        Self: Sized + Iterator + Clone + Debug,
+        Self::Item: Row<T>,
+        T: Add<Output = T> + One + Zero + Sub<Output = T> + Neg<Output = T> + PartialOrd + Copy,
+        Range<T>: Iterator<Item = T>,
     {
-        self.map(move |(x, y)| (x + offset_x, y + offset_y))
+        self.filter_map(move |row| bottom(row).map(|(span, y)| (right_bounds(span, end), y)))
     }
 
-    /// An iterator adapter that creates (x, y) coordinates for the filled long circle.
+    /// An iterator adapter that creates spans of x coordinates for a sector of the filled full circle.
+    ///
+    /// The sector runs counterclockwise from the direction `start` to the direction `end`,
+    /// with the same rules as the [`arc`](Rasterization::arc) adapter. The rows are clipped to
+    /// the pixels whose centers lie in the sector, so a row can be split into two spans.
+    /// Adjacent sectors share no pixels and together fill the full circle.
+    ///
+    /// The directions must not be `(0, 0)`.
     ///
     /// # Examples
     ///
@@ -281,236 +4377,251 @@ pub trait Rasterization: Iterator {
     /// ```rust
     /// use rasterization::{Rasterization, SemicircleFilled};
     ///
-    /// let iter = SemicircleFilled::<i32>::new(2_u64).circle_long(1, -1);
+    /// let iter = SemicircleFilled::<i32>::new(3_u32).sector((1, 0), (-1, -1));
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-1, -1), (-1, 0), (0, -1), (0, 0)]);
+    /// assert_eq!(vec, vec![(0..3, -1), (-1..3, -2), (-2..2, -3)]);
     /// ```
     #[inline]
-    fn circle_long<T>(self, start: T, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn sector<T>(
+        self,
+        start: (T, T),
+        end: (T, T),
+    ) -> impl Iterator<Item = (Range<T>, T)> + Clone + Debug
     where
         Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign + Debug,
-        Range<T>: Iterator<Item = T> + Clone + Debug,
+        T: PrimInt + Signed + Debug,
     {
-        self.flat_map(move |(mut range, y)| {
-            range.start += start;
-            range.end += end;
-            range.flat_map(move |x| [(x, y), (x, -y - T::one())])
+        let wide = |v: T| v.to_i128().unwrap_or_default();
+        let narrow = |v: i128| <T as num::NumCast>::from(v).unwrap_or_else(T::zero);
+        let (start, end) = ((wide(start.0), wide(start.1)), (wide(end.0), wide(end.1)));
+        self.flat_map(move |(range, y)| {
+            let span = wide(range.start)..wide(range.end);
+            let y = wide(y);
+            [y, -y - 1].into_iter().flat_map(move |y| {
+                clip_to_arc(span.clone(), y, start, end)
+                    .into_iter()
+                    .flatten()
+                    .map(move |part| (narrow(part.start)..narrow(part.end), narrow(y)))
+            })
         })
     }
 
-    /// An iterator adapter that creates (x, y) coordinates for the filled top semicircle.
+    /// An iterator adapter that creates (x, y) coordinates for the full circle outline.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<isize>::new(2_u8).semicircle_top();
+    /// let iter = CircleOutline::<i32>::new(2_usize).circle_outline();
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-2, -1), (-1, -1), (0, -1), (1, -1), (-1, -2), (0, -2)]);
+    /// assert_eq!(vec, vec![(1, -1), (-2, -1), (1, 0), (-2, 0), (0, -2), (-1, -2), (0, 1), (-1, 1)]);
     /// ```
     #[inline]
-    fn semicircle_top<T, R>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn circle_outline<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
-        Self: Sized + Iterator<Item = (R, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy,
-        R: Iterator<Item = T> + Clone + Debug,
+        Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(|(range, y)| range.map(move |x| (x, y)))
+        self.flat_map(|(range, y)| {
+            range.flat_map(move |x| {
+                [
+                    (x, y),
+                    (-x - T::one(), y),
+                    (x, -y - T::one()),
+                    (-x - T::one(), -y - T::one()),
+                ]
+            })
+        })
     }
 
-    /// An iterator adapter that creates (x, y) coordinates for the filled bottom semicircle.
+    /// An iterator adapter that creates (x, y) coordinates for the top semicircle outline.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<isize>::new(2_u16).semicircle_bottom();
+    /// let iter = CircleOutline::<isize>::new(2_u8).semicircle_top_outline();
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-2, 0), (-1, 0), (0, 0), (1, 0), (-1, 1), (0, 1)]);
+    /// assert_eq!(vec, vec![(1, -1), (-2, -1), (0, -2), (-1, -2)]);
     /// ```
     #[inline]
-    fn semicircle_bottom<T, R>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn semicircle_top_outline<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
-        Self: Sized + Iterator<Item = (R, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy,
-        R: Iterator<Item = T> + Clone + Debug,
+        Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
+        Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(|(range, y)| range.map(move |x| (x, -y - T::one())))
+        self.flat_map(|(range, y)| range.flat_map(move |x| [(x, y), (-x - T::one(), y)]))
     }
 
-    /// An iterator adapter that creates (x, y) coordinates for the filled top long semicircle.
+    /// An iterator adapter that creates (x, y) coordinates for the bottom semicircle outline.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<isize>::new(2_u32).semicircle_top_long(0, 1);
+    /// let iter = CircleOutline::<isize>::new(2_u16).semicircle_bottom_outline();
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-2, -1), (-1, -1), (0, -1), (1, -1), (2, -1), (-1, -2), (0, -2), (1, -2)]);
+    /// assert_eq!(vec, vec![(1, 0), (-2, 0), (0, 1), (-1, 1)]);
     /// ```
     #[inline]
-    fn semicircle_top_long<T>(
-        self,
-        start: T,
-        end: T,
-    ) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn semicircle_bottom_outline<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
         Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + Debug,
         Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(move |(mut range, y)| {
-            range.start += start;
-            range.end += end;
-            range.map(move |x| (x, y))
+        self.flat_map(|(range, y)| {
+            range.flat_map(move |x| [(x, -y - T::one()), (-x - T::one(), -y - T::one())])
         })
     }
 
-    /// An iterator adapter that creates (x, y) coordinates for the filled bottom long semicircle.
+    /// An iterator adapter that creates the (x, y) coordinates for the circle outline of the first quadrant.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<isize>::new(2_usize).semicircle_bottom_long(0, 1);
+    /// let iter = CircleOutline::<isize>::new(3_u64).first_quadrant_outline();
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-2, 0), (-1, 0), (0, 0), (1, 0), (2, 0), (-1, 1), (0, 1), (1, 1)]);
+    /// assert_eq!(vec, vec![(2, -1), (2, -2), (0, -3), (1, -3)]);
     /// ```
     #[inline]
-    fn semicircle_bottom_long<T>(
-        self,
-        start: T,
-        end: T,
-    ) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn first_quadrant_outline<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
         Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        T: Copy,
         Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(move |(mut range, y)| {
-            range.start += start;
-            range.end += end;
-            range.map(move |x| (x, -y - T::one()))
-        })
+        self.flat_map(|(range, y)| range.map(move |x| (x, y)))
     }
 
-    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the first quadrant.
+    /// An iterator adapter that creates the (x, y) coordinates for the circle outline of the second quadrant.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<isize>::new(3_u64).first_quadrant(0);
+    /// let iter = CircleOutline::<i64>::new(3_u8).second_quadrant_outline();
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(0, -1), (1, -1), (2, -1), (0, -2), (1, -2), (2, -2), (0, -3), (1, -3)]);
+    /// assert_eq!(vec, vec![(-3, -1), (-3, -2), (-1, -3), (-2, -3)]);
     /// ```
     #[inline]
-    fn first_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn second_quadrant_outline<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
         Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
-        T: Add<Output = T> + Zero + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        T: One + Sub<Output = T> + Neg<Output = T> + Copy,
         Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(move |(mut range, y)| {
-            range.start = T::zero();
-            range.end += end;
-            range.map(move |x| (x, y))
-        })
+        self.flat_map(|(range, y)| range.map(move |x| (-x - T::one(), y)))
     }
 
-    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the second quadrant.
+    /// An iterator adapter that creates the (x, y) coordinates for the circle outline of the third quadrant.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<i64>::new(3_u8).second_quadrant(0);
+    /// let iter = CircleOutline::<i64>::new(3_u16).third_quadrant_outline();
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-3, -1), (-2, -1), (-1, -1), (-3, -2), (-2, -2), (-1, -2), (-2, -3), (-1, -3)]);
+    /// assert_eq!(vec, vec![(-3, 0), (-3, 1), (-1, 2), (-2, 2)]);
     /// ```
     #[inline]
-    fn second_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn third_quadrant_outline<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
         Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
-        T: Add<Output = T> + Zero + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        T: One + Sub<Output = T> + Neg<Output = T> + Copy,
         Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(move |(mut range, y)| {
-            range.end = T::zero() + end;
-            range.map(move |x| (x, y))
-        })
+        self.flat_map(|(range, y)| range.map(move |x| (-x - T::one(), -y - T::one())))
     }
 
-    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the third quadrant.
+    /// An iterator adapter that creates the (x, y) coordinates for the circle outline of the fourth quadrant.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<i64>::new(3_u16).third_quadrant(0);
+    /// let iter = CircleOutline::<i64>::new(3_u32).fourth_quadrant_outline();
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(-3, 0), (-2, 0), (-1, 0), (-3, 1), (-2, 1), (-1, 1), (-2, 2), (-1, 2)]);
+    /// assert_eq!(vec, vec![(2, 0), (2, 1), (0, 2), (1, 2)]);
     /// ```
     #[inline]
-    fn third_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn fourth_quadrant_outline<T>(self) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
         Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Zero + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        T: One + Sub<Output = T> + Neg<Output = T> + Copy,
         Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(move |(mut range, y)| {
-            range.end = T::zero() + end;
-            range.map(move |x| (x, -y - T::one()))
-        })
+        self.flat_map(|(range, y)| range.map(move |x| (x, -y - T::one())))
     }
 
-    /// An iterator adapter that creates the (x, y) coordinates for the filled circle of the fourth quadrant.
+    /// An iterator adapter that creates the (x, y) coordinates for an arc of the circle outline.
+    ///
+    /// The arc runs counterclockwise as displayed, from the direction `start` to the direction `end`,
+    /// both given as points relative to the center, for example `(1, 0)` points to the right
+    /// and `(0, -1)` points up. A pixel belongs to the arc if the direction to its center lies
+    /// in the range, the start direction is included and the end direction is excluded.
+    /// The arc wraps around past the right direction if `end` comes before `start`,
+    /// equal directions select the full outline.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if a direction is `(0, 0)`.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```rust
-    /// use rasterization::{Rasterization, SemicircleFilled};
+    /// use rasterization::{CircleOutline, Rasterization};
     ///
-    /// let iter = SemicircleFilled::<i64>::new(3_u32).fourth_quadrant(0);
+    /// let iter = CircleOutline::<i32>::new(3_u32).arc((0, -1), (-1, 0));
     /// let vec = iter.collect::<Vec<_>>();
-    /// assert_eq!(vec, vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1), (0, 2), (1, 2)]);
+    /// assert_eq!(vec, vec![(-3, -1), (-3, -2), (-1, -3), (-2, -3)]);
     /// ```
     #[inline]
-    fn fourth_quadrant<T>(self, end: T) -> impl Iterator<Item = (T, T)> + Clone + Debug
+    fn arc<T>(self, start: (T, T), end: (T, T)) -> impl Iterator<Item = (T, T)> + Clone + Debug
     where
         Self: Sized + Iterator<Item = (Range<T>, T)> + Clone + Debug,
-        T: Add<Output = T> + One + Zero + Sub<Output = T> + Neg<Output = T> + Copy + AddAssign,
+        T: PrimInt + Signed + Debug,
         Range<T>: Iterator<Item = T> + Clone + Debug,
     {
-        self.flat_map(move |(mut range, y)| {
-            range.start = T::zero();
-            range.end += end;
-            range.map(move |x| (x, -y - T::one()))
-        })
+        if start == (T::zero(), T::zero()) || end == (T::zero(), T::zero()) {
+            panic!("The direction of an arc must not be zero");
+        }
+
+        let wide = |(x, y): (T, T)| {
+            (
+                x.to_i128().unwrap_or_default(),
+                y.to_i128().unwrap_or_default(),
+            )
+        };
+        let (start, end) = (wide(start), wide(end));
+        self.circle_outline()
+            .filter(move |&pixel| in_arc(start, end, wide(pixel)))
     }
 
     /// The iterator adapter fills a circle or part of it with a gradient color from crate [colorous].
@@ -572,108 +4683,61 @@ pub trait Rasterization: Iterator {
             )
         })
     }
-}
-
-impl<T: ?Sized> Rasterization for T where T: Iterator {}
 
-impl DoubleEndedIterator for SemicircleFilled<i32> {
+    /// An iterator adapter that creates the colors of pixels from the colors of the vertices.
+    ///
+    /// The colors are interpolated with the weights of each pixel, like those of [`Triangle`](crate::Triangle).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use rasterization::{Rasterization, Triangle};
+    ///
+    /// let colors = [[255, 0, 0], [0, 255, 0], [0, 0, 255]];
+    /// let iter = Triangle::<i32>::new([(0, 0), (4, 0), (0, 4)]).vertex_colors(colors);
+    /// let vec = iter.take(2).collect::<Vec<_>>();
+    /// assert_eq!(vec, vec![(0, 0, [191, 32, 32]), (1, 0, [128, 96, 32])]);
+    /// ```
     #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.x == 0 {
-            return None;
-        }
-
-        loop {
-            let x_tmp = self.x;
-            let y_tmp = self.y;
-            let err_tmp = self.err;
-
-            if err_tmp <= self.y {
-                self.y += 1;
-                self.err += 2 * self.y + 2;
+    fn vertex_colors<T>(
+        self,
+        colors: [[u8; 3]; 3],
+    ) -> impl Iterator<Item = (T, T, [u8; 3])> + Clone + Debug
+    where
+        Self: Sized + Iterator<Item = (T, T, [f32; 3])> + Clone + Debug,
+    {
+        self.map(move |(x, y, weights)| {
+            let channel = |c: usize| {
+                let value = (0..3)
+                    .map(|i| weights[i] * f32::from(colors[i][c]))
+                    .sum::<f32>();
+                (value + 0.5) as u8
             };
 
-            if err_tmp > self.x || self.err > self.y {
-                self.x += 1;
-                self.err += 2 * self.x + 2;
-                if self.y == y_tmp {
-                    return Some((-(self.y + 1)..(self.y + 1), (self.x - 1)));
-                }
-            }
-
-            if x_tmp != self.x {
-                let xy = (-self.y..self.y, (self.x - 1));
-
-                return Some(xy);
-            }
-        }
+            (x, y, [channel(0), channel(1), channel(2)])
+        })
     }
 }
 
-impl DoubleEndedIterator for SemicircleFilled<i64> {
-    #[inline]
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.x == 0 {
-            return None;
-        }
-
-        loop {
-            let x_tmp = self.x;
-            let y_tmp = self.y;
-            let err_tmp = self.err;
-
-            if err_tmp <= self.y {
-                self.y += 1;
-                self.err += 2 * self.y + 2;
-            };
-
-            if err_tmp > self.x || self.err > self.y {
-                self.x += 1;
-                self.err += 2 * self.x + 2;
-                if self.y == y_tmp {
-                    return Some((-(self.y + 1)..(self.y + 1), (self.x - 1)));
-                }
-            }
-
-            if x_tmp != self.x {
-                let xy = (-self.y..self.y, (self.x - 1));
-
-                return Some(xy);
-            }
-        }
-    }
-}
+impl<T: ?Sized> Rasterization for T where T: Iterator {}
 
-impl DoubleEndedIterator for SemicircleFilled<isize> {
+impl<T> DoubleEndedIterator for SemicircleFilled<T>
+where
+    T: PrimInt + Signed,
+{
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.x == 0 {
+        if self.rows.is_zero() {
             return None;
         }
 
-        loop {
-            let x_tmp = self.x;
-            let y_tmp = self.y;
-            let err_tmp = self.err;
-
-            if err_tmp <= self.y {
-                self.y += 1;
-                self.err += 2 * self.y + 2;
-            };
-
-            if err_tmp > self.x || self.err > self.y {
-                self.x += 1;
-                self.err += 2 * self.x + 2;
-                if self.y == y_tmp {
-                    return Some((-(self.y + 1)..(self.y + 1), (self.x - 1)));
-                }
-            }
+        self.rows = self.rows - T::one();
 
-            if x_tmp != self.x {
-                let xy = (-self.y..self.y, (self.x - 1));
+        // The back walks the same path and reads the rows off the steps along the columns.
+        let width = self.back.next_column();
 
-                return Some(xy);
-            }
-        }
+        Some((-width..width, self.back.x - T::one()))
     }
 }
@@ -0,0 +1,19 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::ops::Range;
+
+pub fn sorted(mut vec: Vec<(i32, i32)>) -> Vec<(i32, i32)> {
+    vec.sort();
+    vec
+}
+
+/// Returns the pixels of the spans in the order of the spans.
+pub fn flatten(spans: impl Iterator<Item = (Range<i32>, i32)>) -> Vec<(i32, i32)> {
+    spans.flat_map(|(range, y)| range.map(move |x| (x, y))).collect()
+}
+
+/// Returns the pixels of the spans sorted.
+pub fn pixels(spans: impl Iterator<Item = (Range<i32>, i32)>) -> Vec<(i32, i32)> {
+    sorted(flatten(spans))
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{Annulus, DirectionGradient, Rasterization, SemicircleFilled};
+
+fn ring(outer: u32, inner: u32) -> Vec<(i32, i32)> {
+    let hole = sorted(SemicircleFilled::<i32>::new(inner).circle().collect());
+    sorted(
+        SemicircleFilled::<i32>::new(outer)
+            .circle()
+            .filter(|pixel| hole.binary_search(pixel).is_err())
+            .collect(),
+    )
+}
+
+#[test]
+fn test_annulus_empty() {
+    assert_eq!(Annulus::<i32>::new(0_u32, 0_u32).collect::<Vec<_>>(), vec![]);
+    assert_eq!(Annulus::<i32>::new(5_u32, 5_u32).collect::<Vec<_>>(), vec![]);
+    assert_eq!(Annulus::<i32>::new(5_u32, 8_u32).collect::<Vec<_>>(), vec![]);
+}
+
+#[test]
+fn test_annulus_without_hole() {
+    for radius in 0..30_u32 {
+        let vec = Annulus::<i32>::new(radius, 0_u32).collect::<Vec<_>>();
+        assert_eq!(vec, SemicircleFilled::<i32>::new(radius).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_annulus_pixels() {
+    for outer in 0..40_u32 {
+        for inner in 0..=outer {
+            let circle = sorted(Annulus::<i32>::new(outer, inner).circle().collect());
+            assert_eq!(circle, ring(outer, inner), "outer = {outer}, inner = {inner}");
+            let (lower, upper) = Annulus::<i32>::new(outer, inner).circle().size_hint();
+            assert!(lower <= circle.len() && upper.map_or(true, |upper| upper >= circle.len()));
+        }
+    }
+}
+
+#[test]
+fn test_annulus_rows() {
+    for outer in 0..40_u32 {
+        for inner in 0..=outer {
+            let vec = Annulus::<i32>::new(outer, inner).collect::<Vec<_>>();
+            assert!(vec.iter().all(|(range, _)| !range.is_empty()));
+            for row in -(outer as i32)..0 {
+                assert!(vec.iter().filter(|(_, y)| *y == row).count() <= 2);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_annulus_size_hint() {
+    for outer in 0..30_u32 {
+        for inner in 0..=outer {
+            let mut iter = Annulus::<i32>::new(outer, inner);
+            loop {
+                let (lower, upper) = iter.size_hint();
+                let count = iter.clone().count();
+                assert!(lower <= count && count <= upper.unwrap());
+                if iter.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_annulus_quadrants() {
+    for outer in 0..30_u32 {
+        for inner in 0..=outer {
+            let iter = Annulus::<i32>::new(outer, inner);
+            let ring = ring(outer, inner);
+            let part = |keep: fn(&(i32, i32)) -> bool| ring.iter().copied().filter(keep).collect::<Vec<_>>();
+            assert_eq!(sorted(iter.clone().first_quadrant(0).collect()), part(|&(x, y)| x >= 0 && y < 0));
+            assert_eq!(sorted(iter.clone().second_quadrant(0).collect()), part(|&(x, y)| x < 0 && y < 0));
+            assert_eq!(sorted(iter.clone().third_quadrant(0).collect()), part(|&(x, y)| x < 0 && y >= 0));
+            assert_eq!(sorted(iter.clone().fourth_quadrant(0).collect()), part(|&(x, y)| x >= 0 && y >= 0));
+            assert_eq!(sorted(iter.clone().semicircle_top().collect()), part(|&(_, y)| y < 0));
+            assert_eq!(sorted(iter.clone().semicircle_bottom().collect()), part(|&(_, y)| y >= 0));
+            assert_eq!(pixels(iter.clone().first_quadrant_spans(0)), part(|&(x, y)| x >= 0 && y < 0));
+            assert_eq!(pixels(iter.clone().second_quadrant_spans(0)), part(|&(x, y)| x < 0 && y < 0));
+            assert_eq!(pixels(iter.clone().third_quadrant_spans(0)), part(|&(x, y)| x < 0 && y >= 0));
+            assert_eq!(pixels(iter.fourth_quadrant_spans(0)), part(|&(x, y)| x >= 0 && y >= 0));
+        }
+    }
+}
+
+#[test]
+fn test_annulus_quadrant_size_hint() {
+    let iter = Annulus::<i32>::new(20_u32, 13_u32);
+    let mut quadrant = iter.first_quadrant(0);
+    let mut len = quadrant.clone().count();
+    loop {
+        let (lower, upper) = quadrant.size_hint();
+        assert!(lower <= len && upper.map_or(true, |upper| upper >= len));
+        if quadrant.next().is_none() {
+            break;
+        }
+        len -= 1;
+    }
+    assert_eq!(len, 0);
+}
+
+#[test]
+fn test_annulus_offset_gradient() {
+    let radius = 16_u32;
+    let iter = Annulus::<i32>::new(radius, 9_u32)
+        .circle()
+        .offset(16, 16)
+        .gradient(16, 32, DirectionGradient::Bottom(colorous::BROWN_GREEN));
+    let vec = iter.map(|(x, y, _)| (x - 16, y - 16)).collect::<Vec<_>>();
+    assert_eq!(sorted(vec), ring(radius, 9));
+}
+
+#[test]
+fn test_annulus_sector() {
+    let iter = Annulus::<i32>::new(25_u32, 17_u32);
+    let mut tiles = pixels(iter.clone().sector((1, 0), (-2, -1)));
+    tiles.extend(pixels(iter.sector((-2, -1), (1, 0))));
+    assert_eq!(sorted(tiles), ring(25, 17));
+}
@@ -0,0 +1,151 @@
+mod common;
+
+use common::sorted;
+use rasterization::{CircleOutline, EllipseOutline, Rasterization, SemicircleFilled};
+
+fn degrees(x: i32, y: i32) -> f64 {
+    (-y as f64).atan2(x as f64).to_degrees().rem_euclid(360.0)
+}
+
+const DIRECTIONS: [(i32, i32); 12] = [
+    (1, 0),
+    (3, -1),
+    (1, -1),
+    (0, -1),
+    (-2, -5),
+    (-1, 0),
+    (-7, 2),
+    (-1, 1),
+    (0, 1),
+    (1, 4),
+    (5, 1),
+    (2, -1),
+];
+
+#[test]
+fn test_arc_empty() {
+    let vec = CircleOutline::<i32>::new(0_u8).arc((1, 0), (0, 1)).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_arc_full() {
+    for radius in 0..30_u32 {
+        let iter = CircleOutline::<i32>::new(radius);
+        assert_eq!(iter.clone().arc((1, 0), (1, 0)).collect::<Vec<_>>(), iter.clone().circle_outline().collect::<Vec<_>>());
+        assert_eq!(iter.clone().arc((-2, 3), (-4, 6)).collect::<Vec<_>>(), iter.circle_outline().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_arc_quadrants() {
+    for radius in 0..30_u32 {
+        let iter = CircleOutline::<i32>::new(radius);
+        assert_eq!(sorted(iter.clone().arc((1, 0), (0, -1)).collect()), sorted(iter.clone().first_quadrant_outline().collect()));
+        assert_eq!(sorted(iter.clone().arc((0, -1), (-1, 0)).collect()), sorted(iter.clone().second_quadrant_outline().collect()));
+        assert_eq!(sorted(iter.clone().arc((-1, 0), (0, 1)).collect()), sorted(iter.clone().third_quadrant_outline().collect()));
+        assert_eq!(sorted(iter.clone().arc((0, 1), (1, 0)).collect()), sorted(iter.clone().fourth_quadrant_outline().collect()));
+        assert_eq!(sorted(iter.clone().arc((1, 0), (-1, 0)).collect()), sorted(iter.clone().semicircle_top_outline().collect()));
+        assert_eq!(sorted(iter.clone().arc((-1, 0), (1, 0)).collect()), sorted(iter.semicircle_bottom_outline().collect()));
+    }
+}
+
+#[test]
+fn test_arc_wraparound() {
+    let iter = CircleOutline::<i32>::new(10_u32);
+    let arc = sorted(iter.clone().arc((0, 1), (0, -1)).collect());
+    let right = sorted(iter.clone().first_quadrant_outline().chain(iter.fourth_quadrant_outline()).collect());
+    assert_eq!(arc, right);
+    assert!(arc.iter().all(|&(x, _)| x >= 0));
+}
+
+#[test]
+fn test_arc_matches_angles() {
+    for radius in 1..40_u32 {
+        let outline = CircleOutline::<i32>::new(radius).circle_outline().collect::<Vec<_>>();
+        for start in DIRECTIONS {
+            for end in DIRECTIONS {
+                if start == end {
+                    continue;
+                }
+                let from = degrees(start.0, start.1);
+                let sweep = (degrees(end.0, end.1) - from).rem_euclid(360.0);
+                let expected = outline
+                    .iter()
+                    .copied()
+                    .filter(|&(x, y)| (degrees(2 * x + 1, 2 * y + 1) - from).rem_euclid(360.0) < sweep)
+                    .collect::<Vec<_>>();
+                let arc = CircleOutline::<i32>::new(radius).arc(start, end).collect::<Vec<_>>();
+                assert_eq!(arc, expected, "radius = {radius}, start = {start:?}, end = {end:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_arc_partition() {
+    for radius in 0..40_u32 {
+        let iter = CircleOutline::<i64>::new(radius);
+        let full = iter.clone().circle_outline().count();
+        // The first eleven directions are in counterclockwise order.
+        let ordered = &DIRECTIONS[..11];
+        let mut pixels = Vec::new();
+        for (i, start) in ordered.iter().enumerate() {
+            let end = ordered[(i + 1) % ordered.len()];
+            pixels.extend(iter.clone().arc((start.0 as i64, start.1 as i64), (end.0 as i64, end.1 as i64)));
+        }
+        assert_eq!(pixels.len(), full);
+        pixels.sort();
+        pixels.dedup();
+        assert_eq!(pixels.len(), full);
+    }
+}
+
+#[test]
+fn test_arc_ellipse() {
+    let iter = EllipseOutline::<i32>::new(12_u32, 5_u32);
+    let arc = sorted(iter.clone().arc((1, 0), (0, -1)).collect());
+    assert_eq!(arc, sorted(iter.first_quadrant_outline().collect()));
+}
+
+#[test]
+fn test_arc_size_hint() {
+    let mut iter = CircleOutline::<i32>::new(20_u32).arc((3, -1), (-7, 2));
+    let mut len = iter.clone().count();
+    loop {
+        let (lower, upper) = iter.size_hint();
+        assert!(lower <= len && upper.map_or(true, |upper| upper >= len));
+        if iter.next().is_none() {
+            break;
+        }
+        len -= 1;
+    }
+    assert_eq!(len, 0);
+}
+
+#[test]
+#[should_panic(expected = "The direction of an arc must not be zero")]
+fn test_arc_zero_start() {
+    let _ = CircleOutline::<i32>::new(5_u32).arc((0, 0), (1, 0));
+}
+
+#[test]
+#[should_panic(expected = "The direction of an arc must not be zero")]
+fn test_arc_zero_end() {
+    let _ = CircleOutline::<i32>::new(5_u32).arc((0, -1), (0, 0));
+}
+
+#[test]
+fn test_arc_large_coordinates() {
+    let radius = SemicircleFilled::<i128>::MAX_RADIUS;
+    let iter = CircleOutline::<i128>::new(radius as u128);
+    let quadrant = iter.clone().first_quadrant_outline().take(50).collect::<Vec<_>>();
+    assert_eq!(quadrant[..2], [(radius - 1, -1), (radius - 1, -2)]);
+    let arc = iter.clone().arc((1, 0), (0, -1)).take(50).collect::<Vec<_>>();
+    assert_eq!(arc, quadrant);
+    let arc = iter.clone().arc((i128::MAX, -1), (0, i128::MIN)).take(50).collect::<Vec<_>>();
+    assert_eq!(arc, quadrant);
+    // The start ray runs through the center of the second pixel.
+    let arc = iter.arc((2 * radius - 1, -3), (0, -1)).take(49).collect::<Vec<_>>();
+    assert_eq!(arc, quadrant[1..]);
+}
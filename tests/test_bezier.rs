@@ -0,0 +1,176 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{Bezier, BezierFilled, DirectionGradient, FillRule, Line, Rasterization};
+
+type Point = (i32, i32);
+
+fn has_duplicates(vec: &[Point]) -> bool {
+    let mut sorted = vec.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    sorted.len() != vec.len()
+}
+
+// The point of the cubic curve at `t`.
+fn cubic_point(points: [Point; 4], t: f64) -> (f64, f64) {
+    let u = 1.0 - t;
+    let c = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+    let x = (0..4).map(|i| c[i] * points[i].0 as f64).sum();
+    let y = (0..4).map(|i| c[i] * points[i].1 as f64).sum();
+    (x, y)
+}
+
+const QUADRATICS: [[Point; 3]; 5] = [
+    [(0, 0), (3, 6), (6, 0)],
+    [(-10, 4), (20, -15), (7, 19)],
+    [(0, 0), (40, 0), (40, 40)],
+    [(5, 5), (-30, 2), (5, -1)],
+    [(0, 0), (6, 30), (12, 0)],
+];
+
+const CUBICS: [[Point; 4]; 5] = [
+    [(0, 0), (0, 4), (4, 4), (4, 0)],
+    [(-20, 0), (-5, 30), (5, -30), (20, 0)],
+    [(0, 0), (50, 10), (-10, 40), (30, 30)],
+    [(3, -7), (3, -7), (12, 25), (12, 25)],
+    [(0, 0), (60, 0), (60, 60), (0, 60)],
+];
+
+fn outlines() -> Vec<Vec<Point>> {
+    let mut vec = Vec::new();
+    for [a, b, c] in QUADRATICS {
+        vec.push(Bezier::<i32>::new_quadratic(a, b, c).collect());
+    }
+    for [a, b, c, d] in CUBICS {
+        vec.push(Bezier::<i32>::new_cubic(a, b, c, d).collect());
+    }
+    vec
+}
+
+#[test]
+fn test_bezier_point() {
+    assert_eq!(Bezier::<i32>::new_quadratic((2, -3), (2, -3), (2, -3)).collect::<Vec<_>>(), vec![(2, -3)]);
+    assert_eq!(Bezier::<i32>::new_cubic((2, -3), (2, -3), (2, -3), (2, -3)).collect::<Vec<_>>(), vec![(2, -3)]);
+}
+
+#[test]
+fn test_bezier_straight() {
+    for end in [(9, 0), (0, -7), (13, 5), (-6, 11)] {
+        let line = Line::<i32>::new((0, 0), end).collect::<Vec<_>>();
+        let middle = (end.0 / 2, end.1 / 2);
+        if (2 * middle.0, 2 * middle.1) == end {
+            assert_eq!(Bezier::<i32>::new_quadratic((0, 0), middle, end).collect::<Vec<_>>(), line);
+        }
+        let (first, second) = ((end.0 / 3, end.1 / 3), (2 * end.0 / 3, 2 * end.1 / 3));
+        if (3 * first.0, 3 * first.1) == end {
+            assert_eq!(Bezier::<i32>::new_cubic((0, 0), first, second, end).collect::<Vec<_>>(), line);
+        }
+    }
+}
+
+#[test]
+fn test_bezier_endpoints() {
+    for [a, b, c] in QUADRATICS {
+        let vec = Bezier::<i32>::new_quadratic(a, b, c).collect::<Vec<_>>();
+        assert_eq!((vec.first(), vec.last()), (Some(&a), Some(&c)));
+    }
+    for [a, b, c, d] in CUBICS {
+        let vec = Bezier::<i32>::new_cubic(a, b, c, d).collect::<Vec<_>>();
+        assert_eq!((vec.first(), vec.last()), (Some(&a), Some(&d)));
+    }
+}
+
+#[test]
+fn test_bezier_connected() {
+    for vec in outlines() {
+        assert!(vec.windows(2).all(|w| w[0] != w[1] && (w[1].0 - w[0].0).abs() <= 1 && (w[1].1 - w[0].1).abs() <= 1));
+    }
+}
+
+#[test]
+fn test_bezier_no_duplicates() {
+    for vec in outlines() {
+        assert!(!has_duplicates(&vec), "{vec:?}");
+    }
+}
+
+#[test]
+fn test_bezier_close_to_curve() {
+    for points in CUBICS {
+        let [a, b, c, d] = points;
+        let samples = (0..=500).map(|i| cubic_point(points, i as f64 / 500.0)).collect::<Vec<_>>();
+        for (x, y) in Bezier::<i32>::new_cubic(a, b, c, d) {
+            let distance = samples
+                .iter()
+                .map(|&(px, py)| (px - x as f64).abs().max((py - y as f64).abs()))
+                .fold(f64::MAX, f64::min);
+            assert!(distance < 1.0, "{points:?} {x} {y} {distance}");
+        }
+    }
+}
+
+#[test]
+fn test_bezier_adapters() {
+    let vec = Bezier::<i32>::new_quadratic((0, 0), (3, 6), (6, 0)).offset(10, -10).collect::<Vec<_>>();
+    assert_eq!(vec.first(), Some(&(10, -10)));
+    assert_eq!(vec.last(), Some(&(16, -10)));
+    let vec = Bezier::<i32>::new_quadratic((0, 0), (3, 6), (6, 0))
+        .gradient(0, 6, DirectionGradient::Right(colorous::GREYS))
+        .collect::<Vec<_>>();
+    assert_eq!(vec.len(), 8);
+}
+
+#[test]
+fn test_bezier_filled_chord() {
+    // A straight curve encloses nothing.
+    assert_eq!(BezierFilled::<i32>::new_quadratic((0, 0), (5, 5), (10, 10), FillRule::NonZero).count(), 0);
+    // A closed cubic is filled without its chord.
+    let iter = BezierFilled::<i32>::new_cubic((0, 0), (60, 0), (60, 60), (0, 0), FillRule::NonZero);
+    assert!(iter.count() > 0);
+}
+
+#[test]
+fn test_bezier_filled_matches_curve() {
+    for points in CUBICS {
+        let [a, b, c, d] = points;
+        let mut outline = (0..=500).map(|i| cubic_point(points, i as f64 / 500.0)).collect::<Vec<_>>();
+        outline.push(outline[0]);
+        let filled = pixels(BezierFilled::<i32>::new_cubic(a, b, c, d, FillRule::EvenOdd));
+        for y in -40..80 {
+            for x in -40..80 {
+                let (px, py) = (x as f64 + 0.5, y as f64 + 0.5);
+                let mut inside = false;
+                let mut near = false;
+                for w in outline.windows(2) {
+                    let ((x0, y0), (x1, y1)) = (w[0], w[1]);
+                    let (dx, dy) = (x1 - x0, y1 - y0);
+                    let len2 = dx * dx + dy * dy;
+                    let t = if len2 > 0.0 { (((px - x0) * dx + (py - y0) * dy) / len2).clamp(0.0, 1.0) } else { 0.0 };
+                    near |= (x0 + t * dx - px).hypot(y0 + t * dy - py) < 1.0;
+                    if (py < y0) != (py < y1) && x0 + (py - y0) * dx / dy < px {
+                        inside = !inside;
+                    }
+                }
+                if !near {
+                    assert_eq!(filled.binary_search(&(x, y)).is_ok(), inside, "{points:?} {x} {y}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bezier_filled_spans() {
+    let iter = BezierFilled::<i32>::new_cubic((-20, 0), (-5, 30), (5, -30), (20, 0), FillRule::NonZero);
+    let vec = iter.clone().collect::<Vec<_>>();
+    assert!(vec.iter().all(|(range, _)| !range.is_empty()));
+    assert!(vec.windows(2).all(|w| w[0].1 < w[1].1 || w[0].0.end < w[1].0.start));
+    assert_eq!(sorted(iter.clone().pixels().collect()), pixels(iter));
+}
+
+#[test]
+#[should_panic(expected = "The curve is too large")]
+fn test_bezier_too_large() {
+    let _ = Bezier::<i64>::new_quadratic((0, 0), (1 << 40, 0), (0, 1));
+}
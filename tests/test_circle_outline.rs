@@ -0,0 +1,130 @@
+use rasterization::{CircleOutline, Rasterization, SemicircleFilled};
+
+#[test]
+fn test_circle_outline_new_empty() {
+    let outline_iter = CircleOutline::<i32>::new(0_usize);
+    let vec = outline_iter.collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_circle_outline_new_debug() {
+    let outline = CircleOutline::<i32>::new(2_u32);
+    assert_eq!(
+        format!("{:?}", outline),
+        "CircleOutline { semicircle: SemicircleFilled { front: Cursor { x: -1, y: 1, err: 2 }, back: Cursor { x: -2, y: 0, err: -2 }, rows: 1 }, row: Some((-2..2, -1)) }"
+    );
+}
+
+#[test]
+fn test_circle_outline_new_clone() {
+    let outline_iter = CircleOutline::<i32>::new(10_u32);
+    let outline_iter_clone = outline_iter.clone();
+    assert_eq!(outline_iter, outline_iter_clone);
+}
+
+#[test]
+fn test_circle_outline_new_one() {
+    let outline_iter = CircleOutline::<i64>::new(1_u8);
+    let vec = outline_iter.collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0..1, -1)]);
+}
+
+#[test]
+fn test_circle_outline_circle_outline_empty() {
+    let iter = CircleOutline::<isize>::new(0_u8).circle_outline();
+    let vec = iter.collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_circle_outline_circle_outline_clone() {
+    let iter = CircleOutline::<isize>::new(6_u32).circle_outline();
+    let iter_clone = iter.clone();
+    let vec = iter.collect::<Vec<_>>();
+    let clone_vec = iter_clone.collect::<Vec<_>>();
+    assert_eq!(vec, clone_vec);
+}
+
+#[test]
+fn test_circle_outline_circle_outline_four() {
+    let iter = CircleOutline::<i32>::new(4_u64).circle_outline();
+    let vec = iter.collect::<Vec<_>>();
+    assert_eq!(vec,
+        vec![(3, -1), (-4, -1), (3, 0), (-4, 0), (3, -2), (-4, -2),
+        (3, 1), (-4, 1), (2, -3), (-3, -3), (2, 2), (-3, 2), (0, -4),
+        (-1, -4), (0, 3), (-1, 3), (1, -4), (-2, -4), (1, 3), (-2, 3)]);
+}
+
+#[test]
+fn test_circle_outline_semicircle_top_outline_four() {
+    let iter = CircleOutline::<i64>::new(4_u16).semicircle_top_outline();
+    let vec = iter.collect::<Vec<_>>();
+    assert_eq!(vec,
+        vec![(3, -1), (-4, -1), (3, -2), (-4, -2), (2, -3), (-3, -3),
+        (0, -4), (-1, -4), (1, -4), (-2, -4)]);
+}
+
+#[test]
+fn test_circle_outline_semicircle_bottom_outline_four() {
+    let iter = CircleOutline::<i64>::new(4_u16).semicircle_bottom_outline();
+    let vec = iter.collect::<Vec<_>>();
+    assert_eq!(vec,
+        vec![(3, 0), (-4, 0), (3, 1), (-4, 1), (2, 2), (-3, 2),
+        (0, 3), (-1, 3), (1, 3), (-2, 3)]);
+}
+
+#[test]
+fn test_circle_outline_quadrants_tile_circle_outline() {
+    for radius in 0..64_u32 {
+        let iter = CircleOutline::<i32>::new(radius);
+        let mut quadrants = iter
+            .clone()
+            .first_quadrant_outline()
+            .chain(iter.clone().second_quadrant_outline())
+            .chain(iter.clone().third_quadrant_outline())
+            .chain(iter.clone().fourth_quadrant_outline())
+            .collect::<Vec<_>>();
+        let mut circle = iter.circle_outline().collect::<Vec<_>>();
+        quadrants.sort();
+        circle.sort();
+        assert_eq!(quadrants, circle);
+    }
+}
+
+#[test]
+fn test_circle_outline_outermost_pixels_of_circle() {
+    for radius in 0..128_u32 {
+        let mut disc = SemicircleFilled::<i32>::new(radius).circle().collect::<Vec<_>>();
+        disc.sort();
+        let contains = |p: &(i32, i32)| disc.binary_search(p).is_ok();
+        let mut expected = disc
+            .iter()
+            .filter(|&&(x, y)| {
+                !(contains(&(x - 1, y))
+                    && contains(&(x + 1, y))
+                    && contains(&(x, y - 1))
+                    && contains(&(x, y + 1)))
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        let mut outline = CircleOutline::<i32>::new(radius).circle_outline().collect::<Vec<_>>();
+        let len = outline.len();
+        outline.sort();
+        outline.dedup();
+        assert_eq!(outline.len(), len);
+        expected.sort();
+        assert_eq!(outline, expected);
+    }
+}
+
+#[test]
+fn test_circle_outline_eight_way_symmetric() {
+    for radius in 0..128_u32 {
+        let mut outline = CircleOutline::<i64>::new(radius).circle_outline().collect::<Vec<_>>();
+        outline.sort();
+        let mut transposed = outline.iter().map(|&(x, y)| (y, x)).collect::<Vec<_>>();
+        transposed.sort();
+        assert_eq!(outline, transposed);
+    }
+}
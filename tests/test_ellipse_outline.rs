@@ -0,0 +1,121 @@
+use rasterization::{EllipseOutline, Rasterization, SemiellipseFilled};
+
+fn ring(rx: u32, ry: u32) -> Vec<(i32, i32)> {
+    let mut vec = EllipseOutline::<i32>::new(rx, ry).circle_outline().collect::<Vec<_>>();
+    vec.sort();
+    vec
+}
+
+#[test]
+fn test_ellipse_outline_new_empty() {
+    let vec = EllipseOutline::<i32>::new(0_usize, 0_usize).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+    let vec = EllipseOutline::<i32>::new(3_usize, 0_usize).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_ellipse_outline_new_debug() {
+    let outline = EllipseOutline::<i32>::new(3_u32, 1_u32);
+    assert_eq!(
+        format!("{:?}", outline),
+        "EllipseOutline { semiellipse: SemiellipseFilled { x: 3, y: 1, height: 1, \
+        rx_sq: 9, ry_sq: 1, bound: 36 }, row: Some((-3..3, -1)), width: 0 }"
+    );
+}
+
+#[test]
+fn test_ellipse_outline_new_clone() {
+    let outline_iter = EllipseOutline::<i64>::new(10_u32, 3_u32);
+    let outline_iter_clone = outline_iter.clone();
+    assert_eq!(outline_iter, outline_iter_clone);
+}
+
+#[test]
+fn test_ellipse_outline_semicircle_top_outline_four_two() {
+    let vec = EllipseOutline::<isize>::new(4_u8, 2_u8)
+        .semicircle_top_outline()
+        .collect::<Vec<_>>();
+    assert_eq!(vec, vec![(3, -1), (-4, -1), (0, -2), (-1, -2), (1, -2), (-2, -2), (2, -2), (-3, -2)]);
+}
+
+#[test]
+fn test_ellipse_outline_offset_gradient() {
+    let vec = EllipseOutline::<i32>::new(2_u8, 1_u8)
+        .circle_outline()
+        .offset(2, 1)
+        .gradient(0, 4, rasterization::DirectionGradient::Right(colorous::GREYS))
+        .map(|(x, y, _)| (x, y))
+        .collect::<Vec<_>>();
+    assert_eq!(vec, vec![(2, 0), (1, 0), (2, 1), (1, 1), (3, 0), (0, 0), (3, 1), (0, 1)]);
+}
+
+#[test]
+fn test_ellipse_outline_no_duplicates() {
+    for rx in 0..40_u32 {
+        for ry in 0..40_u32 {
+            let vec = EllipseOutline::<i32>::new(rx, ry).circle_outline().collect::<Vec<_>>();
+            let mut dedup = ring(rx, ry);
+            dedup.dedup();
+            assert_eq!(vec.len(), dedup.len());
+        }
+    }
+}
+
+#[test]
+fn test_ellipse_outline_boundary_of_filled() {
+    for rx in 1..40_u32 {
+        for ry in 1..40_u32 {
+            let mut fill = SemiellipseFilled::<i32>::new(rx, ry).circle().collect::<Vec<_>>();
+            fill.sort();
+            let contains = |p: &(i32, i32)| fill.binary_search(p).is_ok();
+            for (x, y) in ring(rx, ry) {
+                assert!(contains(&(x, y)));
+                assert!(
+                    !(contains(&(x - 1, y))
+                        && contains(&(x + 1, y))
+                        && contains(&(x, y - 1))
+                        && contains(&(x, y + 1)))
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ellipse_outline_connected_and_thin() {
+    for rx in 2..40_u32 {
+        for ry in 2..40_u32 {
+            let ring = ring(rx, ry);
+            let contains = |p: &(i32, i32)| ring.binary_search(p).is_ok();
+            let neighbours = |&(x, y): &(i32, i32)| {
+                [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)]
+                    .into_iter()
+                    .map(move |(dx, dy)| (x + dx, y + dy))
+            };
+
+            let mut visited = vec![ring[0]];
+            let mut stack = vec![ring[0]];
+            while let Some(p) = stack.pop() {
+                for n in neighbours(&p) {
+                    if contains(&n) && !visited.contains(&n) {
+                        visited.push(n);
+                        stack.push(n);
+                    }
+                }
+            }
+            assert_eq!(visited.len(), ring.len(), "rx = {rx}, ry = {ry}");
+
+            for (x, y) in ring.iter().copied() {
+                // Pixels next to their own mirror image are needed on both sides of an axis.
+                let corner = [(-1, 1), (1, 1), (-1, -1), (1, -1)].into_iter().any(|(dx, dy)| {
+                    x + dx != -x - 1
+                        && y + dy != -y - 1
+                        && contains(&(x + dx, y))
+                        && contains(&(x, y + dy))
+                });
+                assert!(!corner, "rx = {rx}, ry = {ry}, pixel = {:?}", (x, y));
+            }
+        }
+    }
+}
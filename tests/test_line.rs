@@ -0,0 +1,153 @@
+use rasterization::{DirectionGradient, Line, Rasterization};
+
+// The pixel `i` lies at the rounded position `i * minor / major` along the minor axis.
+fn reference(start: (i32, i32), end: (i32, i32)) -> Vec<(i32, i32)> {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let (major, minor) = (dx.abs().max(dy.abs()), dx.abs().min(dy.abs()));
+    (0..=major)
+        .map(|i| {
+            let offset = if major == 0 { 0 } else { (2 * i * minor + major) / (2 * major) };
+            if dx.abs() >= dy.abs() {
+                (start.0 + i * dx.signum(), start.1 + offset * dy.signum())
+            } else {
+                (start.0 + offset * dx.signum(), start.1 + i * dy.signum())
+            }
+        })
+        .collect()
+}
+
+fn points() -> impl Iterator<Item = (i32, i32)> + Clone {
+    (-6..=6).flat_map(|x| (-6..=6).map(move |y| (x, y)))
+}
+
+#[test]
+fn test_line_point() {
+    assert_eq!(Line::<i32>::new((3, -4), (3, -4)).collect::<Vec<_>>(), vec![(3, -4)]);
+    assert_eq!(Line::<i32>::new((3, -4), (3, -4)).rev().collect::<Vec<_>>(), vec![(3, -4)]);
+    assert_eq!(Line::<i32>::new_exclusive((3, -4), (3, -4)).collect::<Vec<_>>(), vec![]);
+}
+
+#[test]
+fn test_line_axes() {
+    assert_eq!(Line::<i32>::new((0, 0), (3, 0)).collect::<Vec<_>>(), vec![(0, 0), (1, 0), (2, 0), (3, 0)]);
+    assert_eq!(Line::<i32>::new((0, 0), (0, -3)).collect::<Vec<_>>(), vec![(0, 0), (0, -1), (0, -2), (0, -3)]);
+    assert_eq!(Line::<i32>::new((0, 0), (-3, 3)).collect::<Vec<_>>(), vec![(0, 0), (-1, 1), (-2, 2), (-3, 3)]);
+}
+
+#[test]
+fn test_line_octants() {
+    for start in points() {
+        for end in points() {
+            assert_eq!(Line::<i32>::new(start, end).collect::<Vec<_>>(), reference(start, end), "{start:?} {end:?}");
+        }
+    }
+}
+
+#[test]
+fn test_line_rev() {
+    for start in points() {
+        for end in points() {
+            let mut vec = Line::<i32>::new(start, end).collect::<Vec<_>>();
+            vec.reverse();
+            assert_eq!(Line::<i32>::new(start, end).rev().collect::<Vec<_>>(), vec);
+            let mut vec = Line::<i32>::new_exclusive(start, end).collect::<Vec<_>>();
+            vec.reverse();
+            assert_eq!(Line::<i32>::new_exclusive(start, end).rev().collect::<Vec<_>>(), vec);
+        }
+    }
+}
+
+#[test]
+fn test_line_both_ends() {
+    for end in points() {
+        let line = Line::<i32>::new((1, 2), end);
+        let expected = line.clone().collect::<Vec<_>>();
+        let mut iter = line;
+        let (mut front, mut back) = (Vec::new(), Vec::new());
+        while let Some(point) = iter.next() {
+            front.push(point);
+            back.extend(iter.next_back());
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+#[test]
+fn test_line_exclusive() {
+    for start in points() {
+        for end in points() {
+            let mut vec = Line::<i32>::new(start, end).collect::<Vec<_>>();
+            vec.pop();
+            assert_eq!(Line::<i32>::new_exclusive(start, end).collect::<Vec<_>>(), vec);
+        }
+    }
+}
+
+#[test]
+fn test_line_connected() {
+    for end in points() {
+        let vec = Line::<i32>::new((0, 0), end).collect::<Vec<_>>();
+        assert_eq!(vec.first(), Some(&(0, 0)));
+        assert_eq!(vec.last(), Some(&end));
+        assert!(vec.windows(2).all(|w| (w[1].0 - w[0].0).abs() <= 1 && (w[1].1 - w[0].1).abs() <= 1));
+        assert_eq!(vec.len(), end.0.abs().max(end.1.abs()) as usize + 1);
+    }
+}
+
+#[test]
+fn test_line_len() {
+    let mut iter = Line::<i32>::new((-10, 4), (25, -3));
+    assert_eq!(iter.len(), 36);
+    iter.next();
+    iter.next_back();
+    assert_eq!(iter.len(), 34);
+    assert_eq!(Line::<i32>::new_exclusive((-10, 4), (25, -3)).len(), 35);
+}
+
+#[test]
+fn test_line_adapters() {
+    let vec = Line::<i32>::new((0, 0), (4, 1)).offset(10, 20).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(10, 20), (11, 20), (12, 21), (13, 21), (14, 21)]);
+    let vec = Line::<i32>::new((0, 0), (4, 1))
+        .gradient(0, 4, DirectionGradient::Right(colorous::GREYS))
+        .collect::<Vec<_>>();
+    assert_eq!(vec.len(), 5);
+    assert_eq!((vec[4].0, vec[4].1), (4, 1));
+}
+
+#[test]
+fn test_line_types() {
+    let vec = Line::<i8>::new((-31, 32), (32, -31)).collect::<Vec<_>>();
+    assert_eq!(vec.len(), 64);
+    let vec = Line::<i64>::new((0, 0), (i64::MAX / 2, 1)).take(3).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0, 0), (1, 0), (2, 0)]);
+    let vec = Line::<i64>::new((0, 0), (i64::MAX / 2, 1)).rev().take(2).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(i64::MAX / 2, 1), (i64::MAX / 2 - 1, 1)]);
+}
+
+#[test]
+fn test_line_full_range() {
+    let widen = |(x, y): (i8, i8)| (x as i32, y as i32);
+    for (start, end) in [((-128, -128), (127, 127)), ((-64, -64), (63, 63)), ((127, -128), (-128, 5))] {
+        let line = Line::<i8>::new(start, end);
+        assert_eq!(line.clone().map(widen).collect::<Vec<_>>(), reference(widen(start), widen(end)));
+        let mut rev = line.rev().map(widen).collect::<Vec<_>>();
+        rev.reverse();
+        assert_eq!(rev, reference(widen(start), widen(end)));
+    }
+    let line = Line::<i16>::new((-32768, 3), (32767, -4)).map(|(x, y)| (x as i32, y as i32));
+    assert_eq!(line.collect::<Vec<_>>(), reference((-32768, 3), (32767, -4)));
+    assert_eq!(Line::<i16>::new((0, 0), (20000, 0)).len(), 20001);
+    let line = Line::<i32>::new((-1_500_000_000, 0), (1_500_000_000, 0));
+    assert_eq!(line.len(), 3_000_000_001);
+    assert_eq!(line.clone().take(2).collect::<Vec<_>>(), vec![(-1_500_000_000, 0), (-1_499_999_999, 0)]);
+    assert_eq!(line.rev().take(2).collect::<Vec<_>>(), vec![(1_500_000_000, 0), (1_499_999_999, 0)]);
+    let line = Line::<i128>::new((i128::MIN, 0), (i128::MAX, 1));
+    assert_eq!(line.size_hint(), (usize::MAX, None));
+    assert_eq!(line.clone().take(2).collect::<Vec<_>>(), vec![(i128::MIN, 0), (i128::MIN + 1, 0)]);
+    assert_eq!(line.rev().take(2).collect::<Vec<_>>(), vec![(i128::MAX, 1), (i128::MAX - 1, 1)]);
+}
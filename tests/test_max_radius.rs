@@ -0,0 +1,89 @@
+use rasterization::{CircleOutline, RadiusError, Rasterization, SemicircleCentered, SemicircleFilled};
+
+macro_rules! assert_boundary {
+    ($t:ty, $u:ty) => {
+        let max = SemicircleFilled::<$t>::MAX_RADIUS;
+        assert_eq!(max, (<$t>::MAX - 5) / 3);
+        assert!(SemicircleFilled::<$t>::try_new(max as $u).is_ok());
+        assert_eq!(SemicircleFilled::<$t>::try_new(max as $u + 1), Err(RadiusError::TooLarge { max }));
+
+        let rows = SemicircleFilled::<$t>::new(max as $u).take(100).collect::<Vec<_>>();
+        assert_eq!(rows[0], (-max..max, -1));
+        let rows = SemicircleCentered::<$t>::new(max as $u).take(100).collect::<Vec<_>>();
+        assert_eq!(rows[0], (-max..=max, 0));
+    };
+}
+
+#[test]
+fn test_max_radius_i8() {
+    assert_boundary!(i8, u8);
+    assert_eq!(SemicircleFilled::<i8>::MAX_RADIUS, 40);
+}
+
+#[test]
+fn test_max_radius_i16() {
+    assert_boundary!(i16, u16);
+    assert_eq!(SemicircleFilled::<i16>::MAX_RADIUS, 10_920);
+}
+
+#[test]
+fn test_max_radius_i32() {
+    assert_boundary!(i32, u32);
+    let max = SemicircleFilled::<i32>::MAX_RADIUS;
+    let rows = SemicircleFilled::<i32>::new(max as u32).rev().take(3).collect::<Vec<_>>();
+    assert_eq!(rows.iter().map(|row| row.1).collect::<Vec<_>>(), vec![-max, -max + 1, -max + 2]);
+    assert_eq!(SemicircleFilled::<i32>::MAX_RADIUS, 715_827_880);
+}
+
+#[test]
+fn test_max_radius_i64() {
+    assert_boundary!(i64, u64);
+    assert_eq!(SemicircleFilled::<i64>::MAX_RADIUS, 3_074_457_345_618_258_600);
+}
+
+#[test]
+fn test_max_radius_i128() {
+    assert_boundary!(i128, u128);
+}
+
+#[test]
+fn test_max_radius_isize() {
+    assert_boundary!(isize, usize);
+}
+
+#[test]
+fn test_max_radius_full_iteration() {
+    let max = SemicircleFilled::<i8>::MAX_RADIUS as u8;
+    assert_eq!(SemicircleFilled::<i8>::new(max).count(), 40);
+    assert_eq!(SemicircleFilled::<i8>::new(max).rev().count(), 40);
+    let pixels = SemicircleFilled::<i8>::new(max).map(|(range, _)| 2 * range.len()).sum::<usize>();
+    assert_eq!(SemicircleFilled::<i8>::new(max).circle().count(), pixels);
+    assert_eq!(SemicircleCentered::<i8>::new(max).count(), 41);
+    assert_eq!(CircleOutline::<i8>::new(max).circle_outline().count(), 4 * 56);
+
+    let max = SemicircleFilled::<i16>::MAX_RADIUS as u16;
+    assert_eq!(SemicircleFilled::<i16>::new(max).count(), 10_920);
+    assert_eq!(SemicircleFilled::<i16>::new(max).rev().count(), 10_920);
+    assert_eq!(SemicircleCentered::<i16>::new(max).count(), 10_921);
+    assert_eq!(CircleOutline::<i16>::new(max).count(), 10_920);
+}
+
+#[test]
+fn test_max_radius_size_hint() {
+    let max = SemicircleFilled::<i64>::MAX_RADIUS;
+    let iter = SemicircleFilled::<i64>::new(max as u64);
+    assert_eq!(iter.size_hint(), (max as usize, Some(max as usize)));
+    let iter = SemicircleFilled::<i128>::new(SemicircleFilled::<i128>::MAX_RADIUS as u128);
+    assert_eq!(iter.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn test_max_radius_lazy_adapters() {
+    let max = SemicircleFilled::<i128>::MAX_RADIUS;
+    let vec = SemicircleFilled::<i128>::new(max as u128).circle().take(3).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-max, -1), (-max, 0), (-max + 1, -1)]);
+    let vec = SemicircleFilled::<i128>::new(max as u128).fourth_quadrant(0).take(2).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0, 0), (1, 0)]);
+    let vec = CircleOutline::<i128>::new(max as u128).circle_outline().take(2).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(max - 1, -1), (-max, -1)]);
+}
@@ -0,0 +1,143 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{FillRule, PolygonFilled, Rasterization, SemicircleFilled};
+
+fn fill(points: &[(i32, i32)], rule: FillRule) -> Vec<(i32, i32)> {
+    pixels(PolygonFilled::<i32>::new(points, rule))
+}
+
+// The winding number of the pixel center, or `None` if the center is close to an edge.
+fn winding(points: &[(i32, i32)], (x, y): (i32, i32)) -> Option<i32> {
+    let (px, py) = (x as f64 + 0.5, y as f64 + 0.5);
+    let mut winding = 0;
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let (x0, y0, x1, y1) = (a.0 as f64, a.1 as f64, b.0 as f64, b.1 as f64);
+        if (py < y0) == (py < y1) {
+            continue;
+        }
+        let cross = x0 + (py - y0) * (x1 - x0) / (y1 - y0);
+        if (cross - px).abs() < 1e-9 {
+            return None;
+        }
+        if cross < px {
+            winding += if y1 > y0 { 1 } else { -1 };
+        }
+    }
+    Some(winding)
+}
+
+const STAR: [(i32, i32); 5] = [(10, 0), (16, 19), (0, 7), (20, 7), (4, 19)];
+const CONCAVE: [(i32, i32); 8] = [(0, 0), (12, 0), (12, 11), (7, 11), (7, 4), (4, 4), (4, 13), (0, 13)];
+
+#[test]
+fn test_polygon_empty() {
+    assert_eq!(PolygonFilled::<i32>::new(&[], FillRule::NonZero).count(), 0);
+    assert_eq!(PolygonFilled::<i32>::new(&[(3, 3)], FillRule::NonZero).count(), 0);
+    assert_eq!(PolygonFilled::<i32>::new(&[(0, 0), (5, 5)], FillRule::EvenOdd).count(), 0);
+    assert_eq!(PolygonFilled::<i32>::new(&[(0, 2), (9, 2), (4, 2)], FillRule::NonZero).count(), 0);
+}
+
+#[test]
+fn test_polygon_rectangle() {
+    let vec = PolygonFilled::<i32>::new(&[(-2, -1), (3, -1), (3, 2), (-2, 2)], FillRule::EvenOdd).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-2..3, -1), (-2..3, 0), (-2..3, 1)]);
+}
+
+#[test]
+fn test_polygon_matches_winding() {
+    for points in [&STAR[..], &CONCAVE[..], &[(0, 0), (17, 3), (5, 14)][..], &[(1, 1), (30, 9), (2, 20), (12, 9)][..]] {
+        for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            let filled = fill(points, rule);
+            for y in -2..25 {
+                for x in -2..35 {
+                    let Some(winding) = winding(points, (x, y)) else { continue };
+                    let inside = match rule {
+                        FillRule::EvenOdd => winding % 2 != 0,
+                        FillRule::NonZero => winding != 0,
+                    };
+                    assert_eq!(filled.binary_search(&(x, y)).is_ok(), inside, "{points:?} {rule:?} {x} {y}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_polygon_rules() {
+    let even_odd = fill(&STAR, FillRule::EvenOdd);
+    let non_zero = fill(&STAR, FillRule::NonZero);
+    assert!(even_odd.iter().all(|pixel| non_zero.binary_search(pixel).is_ok()));
+    assert!(even_odd.binary_search(&(10, 11)).is_err());
+    assert!(non_zero.binary_search(&(10, 11)).is_ok());
+    assert_eq!(fill(&CONCAVE, FillRule::EvenOdd), fill(&CONCAVE, FillRule::NonZero));
+}
+
+#[test]
+fn test_polygon_orientation() {
+    for points in [&STAR[..], &CONCAVE[..]] {
+        let mut reversed = points.to_vec();
+        reversed.reverse();
+        for rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            assert_eq!(fill(points, rule), fill(&reversed, rule));
+        }
+    }
+}
+
+#[test]
+fn test_polygon_shared_edges() {
+    // A fan of triangles around a center tiles the octagon without overlaps or gaps.
+    let center = (7, 5);
+    let ring = [(0, 0), (7, -3), (15, 0), (17, 6), (14, 13), (6, 15), (-1, 12), (-4, 5)];
+    let mut tiles = Vec::new();
+    for i in 0..ring.len() {
+        tiles.extend(fill(&[center, ring[i], ring[(i + 1) % ring.len()]], FillRule::NonZero));
+    }
+    assert_eq!(sorted(tiles), fill(&ring, FillRule::NonZero));
+}
+
+#[test]
+fn test_polygon_shared_diagonal() {
+    for size in 1..20 {
+        let mut tiles = fill(&[(0, 0), (size, 0), (0, size)], FillRule::EvenOdd);
+        tiles.extend(fill(&[(size, 0), (size, size), (0, size)], FillRule::EvenOdd));
+        assert_eq!(sorted(tiles), fill(&[(0, 0), (size, 0), (size, size), (0, size)], FillRule::EvenOdd));
+    }
+}
+
+#[test]
+fn test_polygon_spans() {
+    let vec = PolygonFilled::<i32>::new(&STAR, FillRule::EvenOdd).collect::<Vec<_>>();
+    assert!(vec.iter().all(|(range, _)| !range.is_empty()));
+    assert!(vec.windows(2).all(|w| w[0].1 < w[1].1 || w[0].0.end < w[1].0.start));
+    let iter = PolygonFilled::<i32>::new(&STAR, FillRule::EvenOdd);
+    assert_eq!(sorted(iter.clone().pixels().collect()), pixels(iter));
+}
+
+#[test]
+fn test_polygon_circle_rows() {
+    // The square around the disc has the same row convention as the disc spans.
+    for radius in 1..20 {
+        let square = [(-radius, -radius), (radius, -radius), (radius, radius), (-radius, radius)];
+        let rows = PolygonFilled::<i32>::new(&square, FillRule::NonZero).collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2 * radius as usize);
+        for (range, y) in SemicircleFilled::<i32>::new(radius as u32).circle_spans() {
+            let row = rows.iter().find(|row| row.1 == y).unwrap();
+            assert!(row.0.start <= range.start && range.end <= row.0.end);
+            assert!(range.start == row.0.start || y != -1);
+        }
+    }
+}
+
+#[test]
+fn test_polygon_size_hint() {
+    let mut iter = PolygonFilled::<i32>::new(&STAR, FillRule::NonZero);
+    loop {
+        let count = iter.clone().count();
+        assert!(count <= iter.size_hint().1.unwrap());
+        if iter.next().is_none() {
+            break;
+        }
+    }
+}
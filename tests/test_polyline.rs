@@ -0,0 +1,106 @@
+use rasterization::{Line, Polyline, Rasterization};
+
+fn has_duplicates(vec: &[(i32, i32)]) -> bool {
+    let mut sorted = vec.to_vec();
+    sorted.sort();
+    sorted.dedup();
+    sorted.len() != vec.len()
+}
+
+const CONVEX: [(i32, i32); 6] = [(0, 0), (9, -2), (14, 3), (11, 12), (2, 10), (-3, 5)];
+
+#[test]
+fn test_polyline_empty() {
+    assert_eq!(Polyline::<i32>::new(&[]).collect::<Vec<_>>(), vec![]);
+    assert_eq!(Polyline::<i32>::new_closed(&[]).collect::<Vec<_>>(), vec![]);
+}
+
+#[test]
+fn test_polyline_single_point() {
+    assert_eq!(Polyline::<i32>::new(&[(4, -1)]).collect::<Vec<_>>(), vec![(4, -1)]);
+    assert_eq!(Polyline::<i32>::new_closed(&[(4, -1)]).collect::<Vec<_>>(), vec![(4, -1)]);
+    assert_eq!(Polyline::<i32>::new_closed(&[(4, -1), (4, -1)]).collect::<Vec<_>>(), vec![(4, -1)]);
+}
+
+#[test]
+fn test_polyline_line() {
+    for x in -6..=6 {
+        for y in -6..=6 {
+            let points = [(1, 2), (x, y)];
+            let line = Line::<i32>::new(points[0], points[1]).collect::<Vec<_>>();
+            assert_eq!(Polyline::<i32>::new(&points).collect::<Vec<_>>(), line);
+        }
+    }
+}
+
+#[test]
+fn test_polyline_segments() {
+    let points = [(0, 0), (5, 3), (5, 3), (-2, 7), (-2, -4)];
+    let mut expected = points
+        .windows(2)
+        .flat_map(|w| Line::<i32>::new_exclusive(w[0], w[1]))
+        .collect::<Vec<_>>();
+    expected.push((-2, -4));
+    assert_eq!(Polyline::<i32>::new(&points).collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_polyline_joints_once() {
+    let open = Polyline::<i32>::new(&CONVEX).collect::<Vec<_>>();
+    assert!(!has_duplicates(&open));
+    for point in CONVEX {
+        assert_eq!(open.iter().filter(|&&p| p == point).count(), 1);
+    }
+
+    let closed = Polyline::<i32>::new_closed(&CONVEX).collect::<Vec<_>>();
+    assert!(!has_duplicates(&closed));
+    assert_eq!(closed.first(), Some(&(0, 0)));
+    let segments = (0..CONVEX.len())
+        .map(|i| {
+            let (a, b) = (CONVEX[i], CONVEX[(i + 1) % CONVEX.len()]);
+            (b.0 - a.0).abs().max((b.1 - a.1).abs()) as usize
+        })
+        .sum::<usize>();
+    assert_eq!(closed.len(), segments);
+}
+
+#[test]
+fn test_polyline_connected() {
+    let closed = Polyline::<i32>::new_closed(&CONVEX).collect::<Vec<_>>();
+    let mut cycle = closed.clone();
+    cycle.push(closed[0]);
+    assert!(cycle.windows(2).all(|w| (w[1].0 - w[0].0).abs() <= 1 && (w[1].1 - w[0].1).abs() <= 1));
+}
+
+#[test]
+fn test_polyline_size_hint() {
+    for closed in [false, true] {
+        let mut iter = match closed {
+            false => Polyline::<i32>::new(&CONVEX),
+            true => Polyline::<i32>::new_closed(&CONVEX),
+        };
+        loop {
+            let count = iter.clone().count();
+            assert_eq!(iter.size_hint(), (count, Some(count)));
+            if iter.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_polyline_full_range() {
+    let points = [(-128, -128), (127, -128), (127, 127)];
+    assert_eq!(Polyline::<i8>::new(&points).count(), 511);
+    assert_eq!(Polyline::<i8>::new(&points).size_hint(), (511, Some(511)));
+    let points = [(-32768, 0), (32767, 0)];
+    assert_eq!(Polyline::<i16>::new_closed(&points).count(), 131_070);
+}
+
+#[test]
+fn test_polyline_offset() {
+    let points = [(0, 0), (1, 0), (1, 1)];
+    let vec = Polyline::<i64>::new_closed(&points).offset(10, 10).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(10, 10), (11, 10), (11, 11)]);
+}
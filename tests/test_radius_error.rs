@@ -0,0 +1,37 @@
+use rasterization::{RadiusError, SemicircleFilled};
+
+#[test]
+fn test_radius_error_try_new_ok() {
+    let semicircle = SemicircleFilled::<i32>::try_new(7_u32);
+    assert_eq!(semicircle, Ok(SemicircleFilled::<i32>::new(7_u32)));
+    let vec = SemicircleFilled::<i64>::try_new(0_u8).unwrap().collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_radius_error_try_new_conversion() {
+    assert_eq!(SemicircleFilled::<i8>::try_new(128_u16), Err(RadiusError::Conversion));
+    assert_eq!(SemicircleFilled::<i32>::try_new(u64::MAX), Err(RadiusError::Conversion));
+    assert_eq!(SemicircleFilled::<i64>::try_new(u128::MAX), Err(RadiusError::Conversion));
+}
+
+#[test]
+fn test_radius_error_try_new_too_large() {
+    assert_eq!(SemicircleFilled::<i16>::try_new(20_000_u16), Err(RadiusError::TooLarge { max: 10_920 }));
+    assert_eq!(SemicircleFilled::<i32>::try_new(800_000_000_u32), Err(RadiusError::TooLarge { max: 715_827_880 }));
+    assert!(SemicircleFilled::<i32>::try_new(100_000_000_u32).is_ok());
+}
+
+#[test]
+fn test_radius_error_display() {
+    let err = SemicircleFilled::<i16>::try_new(20_000_u64).unwrap_err();
+    assert_eq!(err.to_string(), "Radius is too large, the maximum is 10920");
+    let err = SemicircleFilled::<i8>::try_new(u8::MAX).unwrap_err();
+    assert_eq!(err.to_string(), "Not possible to convert radius to the coordinate type");
+}
+
+#[test]
+#[should_panic(expected = "Not possible to convert radius to 300")]
+fn test_radius_error_new_conversion_panic() {
+    let _ = SemicircleFilled::<i8>::new(300_u16);
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{DirectionGradient, Rasterization, Rectangle, RoundedRect};
+
+// The pixels within the border from the sides, by nested loops.
+fn reference(width: i32, height: i32, border: i32) -> Vec<(i32, i32)> {
+    let mut vec = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            if x < border || y < border || x >= width - border || y >= height - border {
+                vec.push((x, y));
+            }
+        }
+    }
+    sorted(vec)
+}
+
+#[test]
+fn test_rectangle_empty() {
+    assert_eq!(Rectangle::<i32>::new(0_u32, 5_u32).count(), 0);
+    assert_eq!(Rectangle::<i32>::new(5_u32, 0_u32).count(), 0);
+    assert_eq!(Rectangle::<i32>::frame(6_u32, 6_u32, 0_u32).collect::<Vec<_>>(), vec![]);
+    assert_eq!(Rectangle::<i32>::frame(0_u32, 6_u32, 2_u32).count(), 0);
+}
+
+#[test]
+fn test_rectangle_filled() {
+    for width in 0..12_u32 {
+        for height in 0..12_u32 {
+            let rect = pixels(Rectangle::<i32>::new(width, height));
+            assert_eq!(rect, reference(width as i32, height as i32, height as i32));
+        }
+    }
+}
+
+#[test]
+fn test_rectangle_frame() {
+    for width in 0..12_u32 {
+        for height in 0..12_u32 {
+            for border in 0..8_u32 {
+                let frame = pixels(Rectangle::<i32>::frame(width, height, border));
+                assert_eq!(frame, reference(width as i32, height as i32, border as i32), "{width} {height} {border}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rectangle_frame_spans() {
+    for border in 0..6_u32 {
+        let rows = Rectangle::<i32>::frame(9_u32, 7_u32, border).collect::<Vec<_>>();
+        assert!(rows.iter().all(|(range, _)| !range.is_empty()));
+        assert!(rows.windows(2).all(|w| w[0].1 < w[1].1 || w[0].0.end < w[1].0.start));
+    }
+    assert_eq!(Rectangle::<i32>::frame(9_u32, 7_u32, 4_u32), Rectangle::<i32>::new(9_u32, 7_u32));
+}
+
+#[test]
+fn test_rectangle_rounded_rect() {
+    for width in 0..10_u32 {
+        for height in 0..10_u32 {
+            let rect = Rectangle::<i32>::new(width, height).collect::<Vec<_>>();
+            let rounded = RoundedRect::<i32>::new(width, height, 0_u32).filter(|(range, _)| !range.is_empty());
+            assert_eq!(rect, rounded.collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn test_rectangle_len() {
+    for border in 0..6_u32 {
+        let mut iter = Rectangle::<i32>::frame(10_u32, 8_u32, border);
+        loop {
+            assert_eq!(iter.len(), iter.clone().count());
+            if iter.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rectangle_adapters() {
+    let iter = Rectangle::<i32>::frame(5_u32, 4_u32, 1_u32);
+    assert_eq!(iter.clone().pixels().count(), 14);
+    let vec = iter.clone().pixels().offset(10, 20).collect::<Vec<_>>();
+    assert_eq!(vec.first(), Some(&(10, 20)));
+    assert_eq!(vec.last(), Some(&(14, 23)));
+    let vec = iter.pixels().gradient(0, 5, DirectionGradient::Right(colorous::GREYS)).collect::<Vec<_>>();
+    assert_eq!(vec.len(), 14);
+}
+
+#[test]
+#[should_panic(expected = "Not possible to convert width to 200")]
+fn test_rectangle_conversion() {
+    let _ = Rectangle::<i8>::new(200_u32, 5_u32);
+}
@@ -0,0 +1,196 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{Rasterization, RegularPolygon, SemicircleFilled};
+use std::f64::consts::PI;
+
+type Point = (i32, i32);
+
+fn shapes() -> Vec<(u32, RegularPolygon<i32>)> {
+    let mut vec = Vec::new();
+    for radius in [1_u32, 2, 5, 8, 13, 24] {
+        for sides in 3..9 {
+            vec.push((radius, RegularPolygon::<i32>::new(radius, sides, 0.0)));
+            vec.push((radius, RegularPolygon::<i32>::new(radius, sides, 0.3)));
+        }
+        for tips in 2..7 {
+            vec.push((radius, RegularPolygon::<i32>::new_star(radius, radius / 2, tips, 0.0)));
+        }
+    }
+    vec
+}
+
+// The corners of the shape as floating point numbers.
+fn corners(radius: f64, inner_radius: f64, corners: usize, rotation: f64) -> Vec<(f64, f64)> {
+    (0..corners)
+        .map(|i| {
+            let r = if corners % 2 == 0 && i % 2 == 1 { inner_radius } else { radius };
+            let angle = rotation - PI / 2.0 + 2.0 * PI * i as f64 / corners as f64;
+            (r * angle.cos(), r * angle.sin())
+        })
+        .collect()
+}
+
+#[test]
+fn test_regular_polygon_empty() {
+    assert_eq!(RegularPolygon::<i32>::new(0_u32, 6, 0.0).count(), 0);
+    assert_eq!(RegularPolygon::<i32>::new(0_u32, 6, 0.0).outline().count(), 0);
+    assert_eq!(RegularPolygon::<i32>::new_star(0_u32, 0_u32, 5, 0.0).count(), 0);
+}
+
+#[test]
+fn test_regular_polygon_inscribed() {
+    for (radius, shape) in shapes() {
+        let disc = sorted(SemicircleFilled::<i32>::new(radius).circle().collect());
+        assert!(pixels(shape.clone()).iter().all(|pixel| disc.binary_search(pixel).is_ok()), "{shape:?}");
+    }
+    for radius in (1..120_u32).step_by(3) {
+        let disc = sorted(SemicircleFilled::<i32>::new(radius).circle().collect());
+        for (sides, rotation) in [(3, 0.0), (4, 0.785), (5, 0.1), (8, 0.2), (16, 1.3)] {
+            let shape = RegularPolygon::<i32>::new(radius, sides, rotation);
+            assert!(pixels(shape).iter().all(|pixel| disc.binary_search(pixel).is_ok()), "{radius} {sides}");
+        }
+    }
+}
+
+#[test]
+fn test_regular_polygon_matches_corners() {
+    for radius in [5_u32, 12, 20] {
+        for (inner, count, rotation) in [(radius, 3, 0.0), (radius, 6, 0.0), (radius, 5, 1.0), (radius / 3, 10, 0.2)] {
+            let shape = match count % 2 == 0 && inner != radius {
+                true => RegularPolygon::<i32>::new_star(radius, inner, count / 2, rotation),
+                false => RegularPolygon::<i32>::new(radius, count, rotation),
+            };
+            // The corners are kept 0.15 of a pixel inside the circle.
+            let outer = radius as f64 - 0.15;
+            let points = corners(outer, (inner as f64).min(outer), count, rotation);
+            let filled = pixels(shape);
+            let r = radius as i32;
+            for y in -r - 1..=r {
+                for x in -r - 1..=r {
+                    let (px, py) = (x as f64 + 0.5, y as f64 + 0.5);
+                    let mut winding = 0;
+                    let mut near = false;
+                    for i in 0..points.len() {
+                        let ((x0, y0), (x1, y1)) = (points[i], points[(i + 1) % points.len()]);
+                        let (dx, dy) = (x1 - x0, y1 - y0);
+                        near |= ((px - x0) * dy - (py - y0) * dx).abs() / dx.hypot(dy) < 1e-6;
+                        if (py < y0) != (py < y1) && x0 + (py - y0) * dx / dy < px {
+                            winding += if y1 > y0 { 1 } else { -1 };
+                        }
+                    }
+                    if !near {
+                        assert_eq!(filled.binary_search(&(x, y)).is_ok(), winding != 0, "{radius} {count} {x} {y}");
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_regular_polygon_symmetry() {
+    for radius in [3_u32, 7, 16, 25] {
+        for sides in [3, 4, 5, 6, 8] {
+            let vec = pixels(RegularPolygon::<i32>::new(radius, sides, 0.0));
+            assert_eq!(sorted(vec.iter().map(|&(x, y)| (-x - 1, y)).collect()), vec);
+            if sides % 2 == 0 {
+                assert_eq!(sorted(vec.iter().map(|&(x, y)| (x, -y - 1)).collect()), vec);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_regular_polygon_star_of_equal_radii() {
+    for radius in [4_u32, 9, 17] {
+        for tips in 2..7 {
+            let star = RegularPolygon::<i32>::new_star(radius, radius, tips, 0.4).collect::<Vec<_>>();
+            let polygon = RegularPolygon::<i32>::new(radius, 2 * tips, 0.4).collect::<Vec<_>>();
+            assert_eq!(star, polygon);
+        }
+    }
+}
+
+#[test]
+fn test_regular_polygon_outline() {
+    for (_, shape) in shapes() {
+        let filled = pixels(shape.clone());
+        let outline = sorted(shape.outline().collect());
+        let inside = |pixel: &Point| filled.binary_search(pixel).is_ok();
+        assert!(outline.iter().all(inside));
+        for &(x, y) in &filled {
+            let border = [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)].iter().any(|p| !inside(p));
+            assert_eq!(outline.binary_search(&(x, y)).is_ok(), border);
+        }
+    }
+}
+
+#[test]
+fn test_regular_polygon_spans() {
+    let iter = RegularPolygon::<i32>::new_star(20_u32, 7_u32, 5, 0.0);
+    let vec = iter.clone().collect::<Vec<_>>();
+    assert!(vec.iter().all(|(range, _)| !range.is_empty()));
+    assert!(vec.windows(2).all(|w| w[0].1 < w[1].1 || w[0].0.end < w[1].0.start));
+    assert_eq!(sorted(iter.clone().pixels().collect()), pixels(iter));
+}
+
+#[test]
+#[should_panic(expected = "A polygon needs at least three sides")]
+fn test_regular_polygon_two_sides() {
+    let _ = RegularPolygon::<i32>::new(5_u32, 2, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "A star needs at least two tips")]
+fn test_regular_polygon_one_tip() {
+    let _ = RegularPolygon::<i32>::new_star(5_u32, 2_u32, 1, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "Radius is too large")]
+fn test_regular_polygon_too_large() {
+    let _ = RegularPolygon::<i64>::new(1_u64 << 40, 6, 0.0);
+}
+
+#[test]
+fn test_regular_polygon_inscribed_large() {
+    for radius in [1_u64 << 24, 1 << 30, 1 << 36] {
+        let r = radius as i64;
+        // The columns of the disc from the left are its rows from the top.
+        let disc = SemicircleFilled::<i64>::new(radius).rev().take(40).collect::<Vec<_>>();
+        // The first corner points up, so the first rows meet the top of the disc
+        // and are symmetric, as long as the corners stay inside the circle.
+        let shapes = [3, 4, 6, 1000].map(|sides| RegularPolygon::<i64>::new(radius, sides, 0.0));
+        let star = RegularPolygon::<i64>::new_star(radius, radius - 1, 500, 0.0);
+        for shape in shapes.into_iter().chain([star]) {
+            let rows = shape.take(30).collect::<Vec<_>>();
+            assert!(!rows.is_empty());
+            for (span, y) in rows {
+                let (row, _) = &disc[(y + r) as usize];
+                assert!(row.start <= span.start && span.end <= row.end, "{radius} {y}");
+                assert_eq!(span.start, -span.end, "{radius} {y}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_regular_polygon_full_turns() {
+    for turns in [-3.0, 1.0, 100.0] {
+        let turned = RegularPolygon::<i32>::new(20_u32, 5, 0.3 + 2.0 * PI * turns).collect::<Vec<_>>();
+        assert_eq!(turned, RegularPolygon::<i32>::new(20_u32, 5, 0.3).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+#[should_panic(expected = "The rotation is not finite or too large")]
+fn test_regular_polygon_huge_rotation() {
+    let _ = RegularPolygon::<i32>::new(1000_u32, 6, 1e20);
+}
+
+#[test]
+#[should_panic(expected = "The rotation is not finite or too large")]
+fn test_regular_polygon_nan_rotation() {
+    let _ = RegularPolygon::<i32>::new_star(10_u32, 5_u32, 5, f64::NAN);
+}
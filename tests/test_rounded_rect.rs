@@ -0,0 +1,100 @@
+mod common;
+
+use common::sorted;
+use rasterization::{Rasterization, RoundedRect, SemicircleFilled};
+
+fn inside(pixel: (i32, i32), x: i32, y: i32, size: i32) -> bool {
+    (x..x + size).contains(&pixel.0) && (y..y + size).contains(&pixel.1)
+}
+
+#[test]
+fn test_rounded_rect_empty() {
+    assert_eq!(RoundedRect::<i32>::new(0_u32, 0_u32, 0_u32).collect::<Vec<_>>(), vec![]);
+    assert_eq!(RoundedRect::<i32>::new(5_u32, 0_u32, 0_u32).pixels().collect::<Vec<_>>(), vec![]);
+    assert_eq!(RoundedRect::<i32>::new(0_u32, 3_u32, 0_u32).pixels().collect::<Vec<_>>(), vec![]);
+}
+
+#[test]
+fn test_rounded_rect_square_corners() {
+    let vec = RoundedRect::<i32>::new(4_u32, 3_u32, 0_u32).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0..4, 0), (0..4, 1), (0..4, 2)]);
+}
+
+#[test]
+fn test_rounded_rect_circle() {
+    for radius in 0..40_u32 {
+        let r = radius as i32;
+        let rect = sorted(RoundedRect::<i32>::new(2 * radius, 2 * radius, radius).pixels().collect());
+        let circle = sorted(SemicircleFilled::<i32>::new(radius).circle().offset(r, r).collect());
+        assert_eq!(rect, circle);
+    }
+}
+
+#[test]
+fn test_rounded_rect_circle_long() {
+    for radius in 0..30_u32 {
+        for long in 0..10_u32 {
+            let (r, l) = (radius as i32, long as i32);
+            let rect = sorted(RoundedRect::<i32>::new(2 * radius + long, 2 * radius, radius).pixels().collect());
+            let iter = SemicircleFilled::<i32>::new(radius);
+            assert_eq!(rect, sorted(iter.clone().circle_long(0, l).offset(r, r).collect()));
+            assert_eq!(rect, sorted(iter.circle_long(-l, 0).offset(r + l, r).collect()));
+        }
+    }
+}
+
+#[test]
+fn test_rounded_rect_corners() {
+    let (width, height) = (23_u32, 17_u32);
+    let radii = [7_u32, 0, 9, 4];
+    let (w, h) = (width as i32, height as i32);
+    let [a, b, c, d] = radii.map(|r| r as i32);
+    let rect = sorted(RoundedRect::<i32>::with_radii(width, height, radii).pixels().collect());
+    let disc = |r: i32| SemicircleFilled::<i32>::new(r as u32);
+
+    let corner = |x, y, size| rect.iter().copied().filter(|&p| inside(p, x, y, size)).collect::<Vec<_>>();
+    assert_eq!(corner(0, 0, a), sorted(disc(a).second_quadrant(0).offset(a, a).collect()));
+    assert_eq!(corner(w - b, 0, b), sorted(disc(b).first_quadrant(0).offset(w - b, b).collect()));
+    assert_eq!(corner(w - c, h - c, c), sorted(disc(c).fourth_quadrant(0).offset(w - c, h - c).collect()));
+    assert_eq!(corner(0, h - d, d), sorted(disc(d).third_quadrant(0).offset(d, h - d).collect()));
+
+    let corners = [(0, 0, a), (w - b, 0, b), (w - c, h - c, c), (0, h - d, d)];
+    let rest = rect
+        .iter()
+        .filter(|&&p| !corners.iter().any(|&(x, y, size)| inside(p, x, y, size)))
+        .count();
+    let area = (w * h - a * a - b * b - c * c - d * d) as usize;
+    assert_eq!(rest, area);
+}
+
+#[test]
+fn test_rounded_rect_len() {
+    let mut iter = RoundedRect::<i32>::with_radii(30_u32, 20_u32, [10, 10, 3, 8]);
+    assert_eq!(iter.len(), 20);
+    iter.nth(4);
+    assert_eq!(iter.len(), 15);
+    assert_eq!(iter.count(), 15);
+}
+
+#[test]
+fn test_rounded_rect_types() {
+    let wide = RoundedRect::<i64>::with_radii(40_u8, 21_u8, [10, 11, 10, 9]).collect::<Vec<_>>();
+    let narrow = RoundedRect::<i8>::with_radii(40_u8, 21_u8, [10, 11, 10, 9]).collect::<Vec<_>>();
+    let narrow = narrow
+        .into_iter()
+        .map(|(range, y)| (range.start as i64..range.end as i64, y as i64))
+        .collect::<Vec<_>>();
+    assert_eq!(wide, narrow);
+}
+
+#[test]
+#[should_panic(expected = "The corners do not fit into the rectangle")]
+fn test_rounded_rect_corners_too_large() {
+    let _ = RoundedRect::<i32>::with_radii(10_u32, 10_u32, [6, 5, 0, 0]);
+}
+
+#[test]
+#[should_panic(expected = "Not possible to convert width to 200")]
+fn test_rounded_rect_conversion_panic() {
+    let _ = RoundedRect::<i8>::new(200_u8, 10_u8, 1_u8);
+}
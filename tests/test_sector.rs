@@ -0,0 +1,109 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{Rasterization, SemicircleFilled, SemiellipseFilled};
+
+fn degrees(x: i32, y: i32) -> f64 {
+    (-y as f64).atan2(x as f64).to_degrees().rem_euclid(360.0)
+}
+
+// The directions are in counterclockwise order.
+const DIRECTIONS: [(i32, i32); 11] = [
+    (1, 0),
+    (3, -1),
+    (1, -1),
+    (0, -1),
+    (-2, -5),
+    (-1, 0),
+    (-7, 2),
+    (-1, 1),
+    (0, 1),
+    (1, 4),
+    (5, 1),
+];
+
+#[test]
+fn test_sector_empty() {
+    let vec = SemicircleFilled::<i32>::new(0_u8).sector((1, 0), (0, 1)).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_sector_debug() {
+    let iter = SemicircleFilled::<i32>::new(0_u8).sector((1, 0), (0, 1));
+    assert!(format!("{:?}", iter).starts_with("FlatMap"));
+}
+
+#[test]
+fn test_sector_full() {
+    for radius in 0..30_u32 {
+        let iter = SemicircleFilled::<i32>::new(radius);
+        let circle = sorted(iter.clone().circle().collect());
+        assert_eq!(pixels(iter.clone().sector((1, 0), (1, 0))), circle);
+        assert_eq!(pixels(iter.sector((-3, 2), (-6, 4))), circle);
+    }
+}
+
+#[test]
+fn test_sector_quadrants() {
+    for radius in 0..30_u32 {
+        let iter = SemicircleFilled::<i32>::new(radius);
+        assert_eq!(pixels(iter.clone().sector((1, 0), (0, -1))), sorted(iter.clone().first_quadrant(0).collect()));
+        assert_eq!(pixels(iter.clone().sector((0, -1), (-1, 0))), sorted(iter.clone().second_quadrant(0).collect()));
+        assert_eq!(pixels(iter.clone().sector((-1, 0), (0, 1))), sorted(iter.clone().third_quadrant(0).collect()));
+        assert_eq!(pixels(iter.clone().sector((0, 1), (1, 0))), sorted(iter.clone().fourth_quadrant(0).collect()));
+        assert_eq!(pixels(iter.clone().sector((1, 0), (-1, 0))), sorted(iter.clone().semicircle_top().collect()));
+        assert_eq!(pixels(iter.clone().sector((-1, 0), (1, 0))), sorted(iter.semicircle_bottom().collect()));
+    }
+}
+
+#[test]
+fn test_sector_matches_angles() {
+    for radius in 1..30_u32 {
+        let circle = sorted(SemicircleFilled::<i32>::new(radius).circle().collect());
+        for start in DIRECTIONS {
+            for end in DIRECTIONS {
+                if start == end {
+                    continue;
+                }
+                let from = degrees(start.0, start.1);
+                let sweep = (degrees(end.0, end.1) - from).rem_euclid(360.0);
+                let expected = circle
+                    .iter()
+                    .copied()
+                    .filter(|&(x, y)| (degrees(2 * x + 1, 2 * y + 1) - from).rem_euclid(360.0) < sweep)
+                    .collect::<Vec<_>>();
+                let sector = pixels(SemicircleFilled::<i32>::new(radius).sector(start, end));
+                assert_eq!(sector, expected, "radius = {radius}, start = {start:?}, end = {end:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_sector_tiling() {
+    for radius in 0..40_u32 {
+        let iter = SemicircleFilled::<i32>::new(radius);
+        let circle = sorted(iter.clone().circle().collect());
+        let mut tiles = Vec::new();
+        for (i, start) in DIRECTIONS.into_iter().enumerate() {
+            let end = DIRECTIONS[(i + 1) % DIRECTIONS.len()];
+            tiles.extend(pixels(iter.clone().sector(start, end)));
+        }
+        assert_eq!(sorted(tiles), circle);
+    }
+}
+
+#[test]
+fn test_sector_split_rows() {
+    let vec = SemicircleFilled::<i32>::new(4_u32).sector((-1, -1), (1, -1)).collect::<Vec<_>>();
+    let rows = vec.iter().filter(|row| row.1 == -2).cloned().collect::<Vec<_>>();
+    assert_eq!(rows, vec![(-4..-1, -2), (2..4, -2)]);
+    assert!(vec.iter().all(|(range, _)| !range.is_empty()));
+}
+
+#[test]
+fn test_sector_ellipse() {
+    let iter = SemiellipseFilled::<i32>::new(12_u32, 5_u32);
+    assert_eq!(pixels(iter.clone().sector((1, 0), (0, -1))), sorted(iter.first_quadrant(0).collect()));
+}
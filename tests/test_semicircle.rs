@@ -12,7 +12,7 @@ fn test_semicircle_new_debug() {
     let semicircle = SemicircleFilled::<i32>::new(0_u32);
     assert_eq!(
         format!("{:?}", semicircle),
-        "SemicircleFilled { x: 0, y: 0, err: 2 }"
+        "SemicircleFilled { front: Cursor { x: 0, y: 0, err: 2 }, back: Cursor { x: 0, y: 0, err: 2 }, rows: 0 }"
     );
 }
 
@@ -33,8 +33,8 @@ fn test_semicircle_circle_empty() {
 #[test]
 fn test_semicircle_circle_debug() {
     let circle_iter = SemicircleFilled::<isize>::new(7_u16).circle();
-    assert_eq!(format!("{:?}", circle_iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -7, y: 0, err: -12 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", circle_iter), "Pixels { rows: \
+            Map { iter: SemicircleFilled { front: Cursor { x: -7, y: 0, err: -12 }, back: Cursor { x: -7, y: 0, err: -12 }, rows: 7 } }, .. }");
 }
 
 #[test]
@@ -78,8 +78,8 @@ fn test_semicircle_circle_long_empty() {
 #[test]
 fn test_semicircle_circle_long_debug() {
     let circle_long_iter = SemicircleFilled::<i64>::new(7_u16).circle_long(1, 1);
-    assert_eq!(format!("{:?}", circle_long_iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-        Map { iter: SemicircleFilled { x: -7, y: 0, err: -12 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", circle_long_iter), "Pixels { rows: \
+            Map { iter: SemicircleFilled { front: Cursor { x: -7, y: 0, err: -12 }, back: Cursor { x: -7, y: 0, err: -12 }, rows: 7 } }, .. }");
 }
 
 #[test]
@@ -126,8 +126,8 @@ fn test_semicircle_semicircle_top_empty() {
 #[test]
 fn test_semicircle_semicircle_top_debug() {
     let semicircle_top_iter = SemicircleFilled::<i64>::new(20_u128).semicircle_top();
-    assert_eq!(format!("{:?}", semicircle_top_iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -20, y: 0, err: -38 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", semicircle_top_iter), "Pixels { rows: \
+            Map { iter: Map { iter: SemicircleFilled { front: Cursor { x: -20, y: 0, err: -38 }, back: Cursor { x: -20, y: 0, err: -38 }, rows: 20 } } }, .. }");
 }
 
 #[test]
@@ -161,8 +161,8 @@ fn test_semicircle_semicircle_bottom_empty() {
 #[test]
 fn test_semicircle_semicircle_bottom_debug() {
     let semicircle_bottom_iter = SemicircleFilled::<i32>::new(20_u128).semicircle_bottom();
-    assert_eq!(format!("{:?}", semicircle_bottom_iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -20, y: 0, err: -38 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", semicircle_bottom_iter), "Pixels { rows: \
+            Map { iter: FilterMap { iter: SemicircleFilled { front: Cursor { x: -20, y: 0, err: -38 }, back: Cursor { x: -20, y: 0, err: -38 }, rows: 20 } } }, .. }");
 }
 
 #[test]
@@ -198,8 +198,8 @@ fn test_semicircle_semicircle_top_long_empty() {
 #[test]
 fn test_semicircle_semicircle_top_long_debug() {
     let iter = SemicircleFilled::<isize>::new(100_usize).semicircle_top_long(-10, 10);
-    assert_eq!(format!("{:?}", iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -100, y: 0, err: -198 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", iter), "Pixels { rows: \
+            Map { iter: Map { iter: SemicircleFilled { front: Cursor { x: -100, y: 0, err: -198 }, back: Cursor { x: -100, y: 0, err: -198 }, rows: 100 } } }, .. }");
 }
 
 #[test]
@@ -240,8 +240,8 @@ fn test_semicircle_semicircle_bottom_long_empty() {
 #[test]
 fn test_semicircle_semicircle_botton_long_debug() {
     let iter = SemicircleFilled::<isize>::new(100_usize).semicircle_bottom_long(-10, 10);
-    assert_eq!(format!("{:?}", iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -100, y: 0, err: -198 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", iter), "Pixels { rows: \
+            Map { iter: FilterMap { iter: SemicircleFilled { front: Cursor { x: -100, y: 0, err: -198 }, back: Cursor { x: -100, y: 0, err: -198 }, rows: 100 } } }, .. }");
 }
 
 #[test]
@@ -297,8 +297,8 @@ fn test_semicircle_first_quadrant_four() {
 #[test]
 fn test_semicircle_first_quadrant_debug() {
     let iter = SemicircleFilled::<isize>::new(8_usize).first_quadrant(0);
-    assert_eq!(format!("{:?}", iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -8, y: 0, err: -14 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", iter), "Pixels { rows: \
+            Map { iter: Map { iter: SemicircleFilled { front: Cursor { x: -8, y: 0, err: -14 }, back: Cursor { x: -8, y: 0, err: -14 }, rows: 8 } } }, .. }");
 }
 
 #[test]
@@ -359,8 +359,8 @@ fn test_semicircle_second_quadrant_five() {
 #[test]
 fn test_semicircle_second_quadrant_debug() {
     let iter = SemicircleFilled::<isize>::new(8_usize).second_quadrant(0);
-    assert_eq!(format!("{:?}", iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -8, y: 0, err: -14 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", iter), "Pixels { rows: \
+            Map { iter: Map { iter: SemicircleFilled { front: Cursor { x: -8, y: 0, err: -14 }, back: Cursor { x: -8, y: 0, err: -14 }, rows: 8 } } }, .. }");
 }
 
 #[test]
@@ -420,8 +420,8 @@ fn test_semicircle_third_quadrant_five() {
 #[test]
 fn test_semicircle_third_quadrant_debug() {
     let iter = SemicircleFilled::<isize>::new(8_usize).third_quadrant(0);
-    assert_eq!(format!("{:?}", iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -8, y: 0, err: -14 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", iter), "Pixels { rows: \
+            Map { iter: FilterMap { iter: SemicircleFilled { front: Cursor { x: -8, y: 0, err: -14 }, back: Cursor { x: -8, y: 0, err: -14 }, rows: 8 } } }, .. }");
 }
 
 #[test]
@@ -481,8 +481,8 @@ fn test_semicircle_fourth_quadrant_five() {
 #[test]
 fn test_semicircle_fourth_quadrant_debug() {
     let iter = SemicircleFilled::<isize>::new(8_usize).fourth_quadrant(0);
-    assert_eq!(format!("{:?}", iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -8, y: 0, err: -14 } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", iter), "Pixels { rows: \
+            Map { iter: FilterMap { iter: SemicircleFilled { front: Cursor { x: -8, y: 0, err: -14 }, back: Cursor { x: -8, y: 0, err: -14 }, rows: 8 } } }, .. }");
 }
 
 #[test]
@@ -532,8 +532,8 @@ fn test_semicircle_semicircle_top_debug_rev() {
     let semicircle_top_iter = SemicircleFilled::<i64>::new(20_u128)
         .rev()
         .semicircle_top();
-    assert_eq!(format!("{:?}", semicircle_top_iter), "FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-        Map { iter: Rev { iter: SemicircleFilled { x: -20, y: 0, err: -38 } } }) }, frontiter: None, backiter: None } }");
+    assert_eq!(format!("{:?}", semicircle_top_iter), "Pixels { rows: \
+            Map { iter: Map { iter: Rev { iter: SemicircleFilled { front: Cursor { x: -20, y: 0, err: -38 }, back: Cursor { x: -20, y: 0, err: -38 }, rows: 20 } } } }, .. }");
 }
 
 #[test]
@@ -612,6 +612,42 @@ fn test_semicircle_i64_semicircle_top_five_rev() {
         (-3, -1), (-2, -1), (-1, -1), (0, -1), (1, -1), (2, -1), (3, -1), (4, -1)]);
 }
 
+#[test]
+fn test_semicircle_interleaved_five() {
+    let mut iter = SemicircleFilled::<i32>::new(5_u32);
+    assert_eq!(iter.next(), Some((-5..5, -1)));
+    assert_eq!(iter.next_back(), Some((-2..2, -5)));
+    assert_eq!(iter.next(), Some((-5..5, -2)));
+    assert_eq!(iter.next_back(), Some((-3..3, -4)));
+    assert_eq!(iter.next(), Some((-4..4, -3)));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_semicircle_interleaved_matches_forward() {
+    // The outermost rows of the large radii are long enough to be solved instead of stepped.
+    for radius in (0..300_u32).chain([4_099, 20_000]) {
+        let forward = SemicircleFilled::<i32>::new(radius).collect::<Vec<_>>();
+        for pattern in [0b01_u32, 0b011, 0b0111, 0b0110, 0b100] {
+            let mut iter = SemicircleFilled::<i32>::new(radius);
+            let (mut front, mut back) = (Vec::new(), Vec::new());
+            for i in 0.. {
+                let row = if pattern >> (i % 3) & 1 == 1 {
+                    iter.next().map(|row| front.push(row))
+                } else {
+                    iter.next_back().map(|row| back.push(row))
+                };
+                if row.is_none() {
+                    break;
+                }
+            }
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, forward, "radius {radius}, pattern {pattern:b}");
+        }
+    }
+}
+
 #[test]
 fn test_semicircle_circle_offset_empty() {
     let circle_iter = SemicircleFilled::<isize>::new(0_u8).circle().offset(0, 0);
@@ -622,8 +658,8 @@ fn test_semicircle_circle_offset_empty() {
 #[test]
 fn test_semicircle_circle_offset_debug() {
     let circle_iter = SemicircleFilled::<isize>::new(7_u16).circle().offset(7, 7);
-    assert_eq!(format!("{:?}", circle_iter), "Map { iter: FlatMap { inner: FlattenCompat { iter: Fuse { iter: Some(\
-            Map { iter: SemicircleFilled { x: -7, y: 0, err: -12 } }) }, frontiter: None, backiter: None } } }");
+    assert_eq!(format!("{:?}", circle_iter), "\
+            Map { iter: Pixels { rows: Map { iter: SemicircleFilled { front: Cursor { x: -7, y: 0, err: -12 }, back: Cursor { x: -7, y: 0, err: -12 }, rows: 7 } }, .. } }");
 }
 
 #[test]
@@ -0,0 +1,124 @@
+mod common;
+
+use common::sorted;
+use rasterization::{Rasterization, SemicircleCentered};
+
+#[test]
+fn test_semicircle_centered_new_zero() {
+    let vec = SemicircleCentered::<i32>::new(0_usize).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0..=0, 0)]);
+    let vec = SemicircleCentered::<i32>::new(0_usize).circle().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0, 0)]);
+}
+
+#[test]
+fn test_semicircle_centered_new_debug() {
+    let semicircle = SemicircleCentered::<i32>::new(7_u32);
+    assert_eq!(
+        format!("{:?}", semicircle),
+        "SemicircleCentered { cursor: Cursor { x: -7, y: 0, err: -12 } }"
+    );
+}
+
+#[test]
+fn test_semicircle_centered_new_clone() {
+    let semicircle_iter = SemicircleCentered::<i64>::new(10_u32);
+    let semicircle_iter_clone = semicircle_iter.clone();
+    assert_eq!(semicircle_iter, semicircle_iter_clone);
+}
+
+#[test]
+fn test_semicircle_centered_new_five() {
+    let vec = SemicircleCentered::<isize>::new(5_u8).collect::<Vec<_>>();
+    assert_eq!(vec,
+        vec![(-5..=5, 0), (-5..=5, -1), (-5..=5, -2), (-4..=4, -3), (-3..=3, -4), (-2..=2, -5)]);
+}
+
+#[test]
+fn test_semicircle_centered_circle_one() {
+    let vec = SemicircleCentered::<i32>::new(1_u16).circle().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-1, 0), (0, 0), (1, 0), (0, -1), (0, 1)]);
+}
+
+#[test]
+fn test_semicircle_centered_rasterization_circle_one() {
+    let vec = Rasterization::circle(SemicircleCentered::<i32>::new(1_u16)).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-1, 0), (0, 0), (1, 0), (0, -1), (0, 1)]);
+}
+
+#[test]
+fn test_semicircle_centered_circle_spans_one() {
+    let vec = SemicircleCentered::<i32>::new(1_u16).circle_spans().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-1..2, 0), (0..1, -1), (0..1, 1)]);
+    let vec = SemicircleCentered::<i32>::new(1_u16).semicircle_top_spans().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-1..2, 0), (0..1, -1)]);
+    let vec = SemicircleCentered::<i32>::new(1_u16).semicircle_bottom_spans().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(0..1, 1)]);
+}
+
+#[test]
+fn test_semicircle_centered_circle_two() {
+    let vec = SemicircleCentered::<i32>::new(2_u16).circle().collect::<Vec<_>>();
+    assert_eq!(vec,
+        vec![(-2, 0), (-1, 0), (0, 0), (1, 0), (2, 0), (-2, -1), (-2, 1),
+        (-1, -1), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 1), (2, -1), (2, 1),
+        (-1, -2), (-1, 2), (0, -2), (0, 2), (1, -2), (1, 2)]);
+}
+
+#[test]
+fn test_semicircle_centered_circle_long_one() {
+    let vec = SemicircleCentered::<i64>::new(1_u32).circle_long(-1, 1).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-2, 0), (-1, 0), (0, 0), (1, 0), (2, 0), (-1, -1), (-1, 1),
+        (0, -1), (0, 1), (1, -1), (1, 1)]);
+}
+
+#[test]
+fn test_semicircle_centered_circle_symmetric() {
+    for radius in 0..100_u32 {
+        let circle = sorted(SemicircleCentered::<i32>::new(radius).circle().collect());
+        let mut dedup = circle.clone();
+        dedup.dedup();
+        assert_eq!(dedup, circle);
+        assert_eq!(circle.iter().map(|&(x, _)| x).min(), Some(-(radius as i32)));
+        assert_eq!(circle.iter().map(|&(x, _)| x).max(), Some(radius as i32));
+        assert_eq!(sorted(circle.iter().map(|&(x, y)| (-x, y)).collect()), circle);
+        assert_eq!(sorted(circle.iter().map(|&(x, y)| (x, -y)).collect()), circle);
+        assert_eq!(sorted(circle.iter().map(|&(x, y)| (y, x)).collect()), circle);
+    }
+}
+
+#[test]
+fn test_semicircle_centered_parts_tile_circle() {
+    for radius in 0..64_u32 {
+        let iter = SemicircleCentered::<i32>::new(radius);
+        let circle = sorted(iter.clone().circle().collect());
+        let halves = sorted(iter.clone().semicircle_top().chain(iter.clone().semicircle_bottom()).collect());
+        let quadrants = sorted(
+            iter.clone()
+                .first_quadrant(0)
+                .chain(iter.clone().second_quadrant(0))
+                .chain(iter.clone().third_quadrant(0))
+                .chain(iter.clone().fourth_quadrant(0))
+                .collect(),
+        );
+        assert_eq!(halves, circle);
+        assert_eq!(quadrants, circle);
+    }
+}
+
+#[test]
+fn test_semicircle_centered_spans_match_pixels() {
+    for radius in 0..64_u32 {
+        let iter = SemicircleCentered::<i32>::new(radius);
+        let circle = sorted(iter.clone().circle().collect());
+        let spans = sorted(
+            iter.clone()
+                .circle_spans()
+                .flat_map(|(span, y)| span.map(move |x| (x, y)))
+                .collect(),
+        );
+        assert_eq!(spans, circle);
+        let width = 2 * radius as usize + 1;
+        assert_eq!(iter.circle_tall(0, 1).count(), circle.len() + width);
+    }
+}
@@ -0,0 +1,76 @@
+use rasterization::{CircleOutline, Rasterization, SemicircleCentered, SemicircleFilled};
+use std::ops::Range;
+
+type Rows = Vec<(Range<i128>, i128)>;
+type Pixels = Vec<(i128, i128)>;
+
+macro_rules! assert_same_output {
+    ($t:ty, $radii:expr) => {
+        let widen_rows = |iter: &mut dyn Iterator<Item = (Range<$t>, $t)>| -> Rows {
+            iter.map(|(range, y)| (range.start as i128..range.end as i128, y as i128)).collect()
+        };
+        let widen_pixels = |iter: &mut dyn Iterator<Item = ($t, $t)>| -> Pixels {
+            iter.map(|(x, y)| (x as i128, y as i128)).collect()
+        };
+
+        for radius in $radii {
+            assert_eq!(
+                widen_rows(&mut SemicircleFilled::<$t>::new(radius)),
+                SemicircleFilled::<i128>::new(radius).collect::<Rows>()
+            );
+            assert_eq!(
+                widen_rows(&mut SemicircleFilled::<$t>::new(radius).rev()),
+                SemicircleFilled::<i128>::new(radius).rev().collect::<Rows>()
+            );
+            assert_eq!(
+                widen_pixels(&mut SemicircleFilled::<$t>::new(radius).circle()),
+                SemicircleFilled::<i128>::new(radius).circle().collect::<Pixels>()
+            );
+            assert_eq!(
+                widen_pixels(&mut CircleOutline::<$t>::new(radius).circle_outline()),
+                CircleOutline::<i128>::new(radius).circle_outline().collect::<Pixels>()
+            );
+            assert_eq!(
+                widen_pixels(&mut SemicircleCentered::<$t>::new(radius).circle()),
+                SemicircleCentered::<i128>::new(radius).circle().collect::<Pixels>()
+            );
+        }
+    };
+}
+
+#[test]
+fn test_semicircle_types_i8() {
+    assert_same_output!(i8, 0..=40_u8);
+}
+
+#[test]
+fn test_semicircle_types_i16() {
+    assert_same_output!(i16, 0..100_u16);
+}
+
+#[test]
+fn test_semicircle_types_i32() {
+    assert_same_output!(i32, 0..100_u16);
+}
+
+#[test]
+fn test_semicircle_types_i64() {
+    assert_same_output!(i64, 0..100_u16);
+}
+
+#[test]
+fn test_semicircle_types_isize() {
+    assert_same_output!(isize, 0..100_u16);
+}
+
+#[test]
+fn test_semicircle_types_i16_sprite() {
+    let vec = SemicircleFilled::<i16>::new(5_u8).collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-5..5, -1), (-5..5, -2), (-4..4, -3), (-3..3, -4), (-2..2, -5)]);
+}
+
+#[test]
+#[should_panic(expected = "Radius is too large")]
+fn test_semicircle_types_i8_too_large() {
+    let _ = SemicircleFilled::<i8>::new(42_u8);
+}
@@ -0,0 +1,105 @@
+use rasterization::{Rasterization, SemiellipseFilled};
+
+#[test]
+fn test_semiellipse_new_empty() {
+    let vec = SemiellipseFilled::<i32>::new(0_usize, 0_usize).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+    let vec = SemiellipseFilled::<i32>::new(0_usize, 5_usize).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+    let vec = SemiellipseFilled::<i32>::new(5_usize, 0_usize).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_semiellipse_new_debug() {
+    let semiellipse = SemiellipseFilled::<i32>::new(3_u32, 2_u32);
+    assert_eq!(
+        format!("{:?}", semiellipse),
+        "SemiellipseFilled { x: 3, y: 0, height: 2, rx_sq: 9, ry_sq: 4, bound: 144 }"
+    );
+}
+
+#[test]
+fn test_semiellipse_new_clone() {
+    let semiellipse_iter = SemiellipseFilled::<i64>::new(10_u32, 7_u32);
+    let semiellipse_iter_clone = semiellipse_iter.clone();
+    assert_eq!(semiellipse_iter, semiellipse_iter_clone);
+}
+
+#[test]
+#[should_panic(expected = "Radius is too large")]
+fn test_semiellipse_new_too_large() {
+    let _ = SemiellipseFilled::<i64>::new(1_u64 << 40, 1_u64 << 40);
+}
+
+#[test]
+fn test_semiellipse_new_large() {
+    for (rx, ry) in [(200_u32, 200_u32), (20_000, 20_000), (30_000, 7)] {
+        let vec = SemiellipseFilled::<i32>::new(rx, ry)
+            .map(|(range, y)| (range.start as i64..range.end as i64, y as i64))
+            .collect::<Vec<_>>();
+        assert_eq!(vec, SemiellipseFilled::<i64>::new(rx, ry).collect::<Vec<_>>());
+    }
+    let vec = SemiellipseFilled::<i8>::new(127_u8, 127_u8)
+        .map(|(range, y)| (range.start as i64..range.end as i64, y as i64))
+        .collect::<Vec<_>>();
+    assert_eq!(vec, SemiellipseFilled::<i64>::new(127_u8, 127_u8).collect::<Vec<_>>());
+    let max = i32::MAX as u32;
+    let mut iter = SemiellipseFilled::<i32>::new(max, max);
+    assert_eq!(iter.len(), i32::MAX as usize);
+    assert_eq!(iter.next(), Some((-i32::MAX..i32::MAX, -1)));
+}
+
+#[test]
+fn test_semiellipse_new_four_ten() {
+    let vec = SemiellipseFilled::<isize>::new(4_u8, 10_u8).collect::<Vec<_>>();
+    assert_eq!(vec,
+        vec![(-4..4, -1), (-4..4, -2), (-4..4, -3), (-4..4, -4), (-4..4, -5),
+        (-3..3, -6), (-3..3, -7), (-3..3, -8), (-2..2, -9), (-1..1, -10)]);
+}
+
+#[test]
+fn test_semiellipse_circle_three_two() {
+    let vec = SemiellipseFilled::<i32>::new(3_u16, 2_u16).circle().collect::<Vec<_>>();
+    assert_eq!(vec,
+        vec![(-3, -1), (-3, 0), (-2, -1), (-2, 0), (-1, -1), (-1, 0),
+        (0, -1), (0, 0), (1, -1), (1, 0), (2, -1), (2, 0), (-2, -2),
+        (-2, 1), (-1, -2), (-1, 1), (0, -2), (0, 1), (1, -2), (1, 1)]);
+}
+
+#[test]
+fn test_semiellipse_first_quadrant_offset() {
+    let vec = SemiellipseFilled::<i64>::new(3_u64, 2_u64)
+        .first_quadrant(0)
+        .offset(3, 2)
+        .collect::<Vec<_>>();
+    assert_eq!(vec, vec![(3, 1), (4, 1), (5, 1), (3, 0), (4, 0)]);
+}
+
+#[test]
+fn test_semiellipse_bounding_box() {
+    for rx in 1..40_u32 {
+        for ry in 1..40_u32 {
+            let vec = SemiellipseFilled::<i32>::new(rx, ry).collect::<Vec<_>>();
+            assert_eq!(vec.len(), ry as usize);
+            assert_eq!(vec[0].0, -(rx as i32)..rx as i32);
+            assert!(vec.windows(2).all(|w| w[1].0.end <= w[0].0.end && !w[1].0.is_empty()));
+        }
+    }
+}
+
+#[test]
+fn test_semiellipse_transposed() {
+    for rx in 1..40_u32 {
+        for ry in 1..40_u32 {
+            let mut vec = SemiellipseFilled::<i32>::new(rx, ry).circle().collect::<Vec<_>>();
+            let mut transposed = SemiellipseFilled::<i32>::new(ry, rx)
+                .circle()
+                .map(|(x, y)| (y, x))
+                .collect::<Vec<_>>();
+            vec.sort();
+            transposed.sort();
+            assert_eq!(vec, transposed);
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use rasterization::{
+    CircleOutline, EllipseOutline, Rasterization, SemicircleCentered, SemicircleFilled,
+    SemiellipseFilled,
+};
+
+fn assert_exact<I: ExactSizeIterator>(mut iter: I) {
+    let mut len = iter.len();
+    assert_eq!(iter.size_hint(), (len, Some(len)));
+    while iter.next().is_some() {
+        len -= 1;
+        assert_eq!(iter.len(), len);
+    }
+    assert_eq!(len, 0);
+}
+
+fn assert_bounds<I: Iterator + Clone>(mut iter: I) {
+    let mut len = iter.clone().count();
+    loop {
+        let (lower, upper) = iter.size_hint();
+        assert!(lower <= len && upper.map_or(true, |upper| upper >= len));
+        if iter.next().is_none() {
+            break;
+        }
+        len -= 1;
+    }
+    assert_eq!(len, 0);
+}
+
+fn assert_counted<I: Iterator + Clone>(mut iter: I) {
+    let mut len = iter.clone().count();
+    loop {
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+        if iter.next().is_none() {
+            break;
+        }
+        len -= 1;
+    }
+    assert_eq!(len, 0);
+}
+
+macro_rules! assert_pixel_counts {
+    ($iter:expr) => {
+        let iter = $iter;
+        assert_counted(iter.clone().circle());
+        assert_counted(iter.clone().circle_long(-2, 3));
+        assert_counted(iter.clone().semicircle_top());
+        assert_counted(iter.clone().semicircle_bottom());
+        assert_counted(iter.clone().semicircle_top_long(-1, 1));
+        assert_counted(iter.clone().semicircle_bottom_long(2, -3));
+        assert_counted(iter.clone().first_quadrant(2));
+        assert_counted(iter.clone().second_quadrant(-1));
+        assert_counted(iter.clone().third_quadrant(1));
+        assert_counted(iter.fourth_quadrant(0));
+    };
+}
+
+macro_rules! assert_outline_bounds {
+    ($iter:expr) => {
+        let iter = $iter;
+        assert_exact(iter.clone());
+        assert_bounds(iter.clone().circle_outline());
+        assert_bounds(iter.clone().semicircle_top_outline());
+        assert_bounds(iter.clone().semicircle_bottom_outline());
+        assert_bounds(iter.clone().first_quadrant_outline());
+        assert_bounds(iter.clone().second_quadrant_outline());
+        assert_bounds(iter.clone().third_quadrant_outline());
+        assert_bounds(iter.fourth_quadrant_outline());
+    };
+}
+
+#[test]
+fn test_size_hint_semicircle_filled() {
+    for radius in 0..300_u32 {
+        let mut iter = SemicircleFilled::<i32>::new(radius);
+        let mut len = radius as usize;
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+        while iter.next().is_some() {
+            len -= 1;
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
+
+#[test]
+fn test_size_hint_semicircle_filled_rev() {
+    for radius in 0..300_u32 {
+        let mut iter = SemicircleFilled::<i64>::new(radius).rev();
+        let mut len = radius as usize;
+        assert_eq!(iter.size_hint(), (len, Some(len)));
+        while iter.next().is_some() {
+            len -= 1;
+            assert_eq!(iter.size_hint(), (len, Some(len)));
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
+
+#[test]
+fn test_size_hint_semicircle_filled_large() {
+    let iter = SemicircleFilled::<i32>::new(99_999_999_u32);
+    assert_eq!(iter.size_hint(), (99_999_999, Some(99_999_999)));
+    let iter = SemicircleCentered::<i32>::new(99_999_999_u32);
+    assert_eq!(iter.len(), 100_000_000);
+}
+
+#[test]
+fn test_size_hint_semicircle_centered() {
+    for radius in 0..300_u32 {
+        assert_exact(SemicircleCentered::<isize>::new(radius));
+    }
+}
+
+#[test]
+fn test_size_hint_semiellipse() {
+    for rx in 0..30_u32 {
+        for ry in 0..30_u32 {
+            assert_exact(SemiellipseFilled::<i32>::new(rx, ry));
+        }
+    }
+}
+
+#[test]
+fn test_size_hint_pixels() {
+    for radius in 0..50_u32 {
+        assert_pixel_counts!(SemicircleFilled::<i32>::new(radius));
+        assert_pixel_counts!(SemicircleFilled::<i64>::new(radius).rev());
+        assert_pixel_counts!(SemicircleCentered::<i32>::new(radius));
+        assert_pixel_counts!(SemiellipseFilled::<i32>::new(radius, radius / 3));
+    }
+}
+
+#[test]
+fn test_size_hint_outlines() {
+    for radius in 0..50_u32 {
+        assert_outline_bounds!(CircleOutline::<i32>::new(radius));
+        assert_outline_bounds!(EllipseOutline::<i32>::new(radius, radius / 2 + 1));
+    }
+}
+
+#[test]
+fn test_size_hint_circle_large() {
+    let iter = SemicircleFilled::<i64>::new(60_000_u32);
+    let width = iter.clone().map(|(range, _)| (range.end - range.start) as usize).sum::<usize>();
+    let mut circle = iter.circle();
+    assert_eq!(circle.size_hint(), (2 * width, Some(2 * width)));
+    circle.nth(999);
+    let len = 2 * width - 1_000;
+    assert_eq!(circle.size_hint(), (len, Some(len)));
+    let mut top = SemicircleFilled::<i64>::new(60_000_u32).semicircle_top();
+    top.nth(119_998);
+    assert_eq!(top.size_hint(), (width - 119_999, Some(width - 119_999)));
+}
+
+#[test]
+fn test_size_hint_circle_lazy() {
+    let mut iter = SemicircleFilled::<i64>::new(50_000_000_u32).circle();
+    assert_eq!(iter.size_hint(), (0, None));
+    assert_eq!(iter.next(), Some((-50_000_000, -1)));
+    assert_eq!(iter.size_hint(), (199_999_999, None));
+    let iter = SemicircleFilled::<i32>::new(7_u8).circle().offset(3, 4);
+    assert_bounds(iter);
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use common::{flatten, pixels, sorted};
+use rasterization::{Rasterization, SemicircleCentered, SemicircleFilled, SemiellipseFilled};
+
+#[test]
+fn test_spans_circle_spans_empty() {
+    let vec = SemicircleFilled::<i32>::new(0_u8).circle_spans().collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_spans_circle_spans_debug() {
+    let iter = SemicircleFilled::<isize>::new(7_u16).semicircle_top_spans();
+    assert_eq!(format!("{:?}", iter), "Map { iter: SemicircleFilled { front: Cursor { x: -7, y: 0, err: -12 }, back: Cursor { x: -7, y: 0, err: -12 }, rows: 7 } }");
+}
+
+#[test]
+fn test_spans_circle_spans_clone() {
+    let iter = SemicircleFilled::<i64>::new(6_u32).circle_spans();
+    let iter_clone = iter.clone();
+    assert_eq!(iter.collect::<Vec<_>>(), iter_clone.collect::<Vec<_>>());
+}
+
+#[test]
+fn test_spans_circle_spans_three() {
+    let vec = SemicircleFilled::<i32>::new(3_u64).circle_spans().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-3..3, -1), (-3..3, 0), (-3..3, -2), (-3..3, 1), (-2..2, -3), (-2..2, 2)]);
+}
+
+#[test]
+fn test_spans_circle_spans_centered_two() {
+    let vec = SemicircleCentered::<i32>::new(2_u64).circle_spans().collect::<Vec<_>>();
+    assert_eq!(vec, vec![(-2..3, 0), (-2..3, -1), (-2..3, 1), (-1..2, -2), (-1..2, 2)]);
+}
+
+macro_rules! assert_same_geometry {
+    ($iter:expr) => {
+        let iter = $iter;
+        assert_eq!(pixels(iter.clone().circle_spans()), sorted(iter.clone().circle().collect()));
+        assert_eq!(
+            pixels(iter.clone().circle_long_spans(-2, 3)),
+            sorted(iter.clone().circle_long(-2, 3).collect())
+        );
+        assert_eq!(flatten(iter.clone().semicircle_top_spans()), iter.clone().semicircle_top().collect::<Vec<_>>());
+        assert_eq!(
+            flatten(iter.clone().semicircle_bottom_spans()),
+            iter.clone().semicircle_bottom().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            flatten(iter.clone().semicircle_top_long_spans(-1, 1)),
+            iter.clone().semicircle_top_long(-1, 1).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            flatten(iter.clone().semicircle_bottom_long_spans(-1, 1)),
+            iter.clone().semicircle_bottom_long(-1, 1).collect::<Vec<_>>()
+        );
+        assert_eq!(flatten(iter.clone().first_quadrant_spans(2)), iter.clone().first_quadrant(2).collect::<Vec<_>>());
+        assert_eq!(flatten(iter.clone().second_quadrant_spans(0)), iter.clone().second_quadrant(0).collect::<Vec<_>>());
+        assert_eq!(flatten(iter.clone().third_quadrant_spans(1)), iter.clone().third_quadrant(1).collect::<Vec<_>>());
+        assert_eq!(flatten(iter.clone().fourth_quadrant_spans(0)), iter.fourth_quadrant(0).collect::<Vec<_>>());
+    };
+}
+
+#[test]
+fn test_spans_same_geometry_as_pixels() {
+    for radius in 0..40_u32 {
+        assert_same_geometry!(SemicircleFilled::<i32>::new(radius));
+        assert_same_geometry!(SemicircleCentered::<i32>::new(radius));
+        assert_same_geometry!(SemiellipseFilled::<i32>::new(radius, radius / 2 + 1));
+    }
+}
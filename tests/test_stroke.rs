@@ -0,0 +1,91 @@
+mod common;
+
+use common::sorted;
+use rasterization::{Annulus, Rasterization, SemicircleFilled, StrokeAlignment};
+
+fn disc(radius: i32) -> Vec<(i32, i32)> {
+    sorted(SemicircleFilled::<i32>::new(radius.max(0) as u32).circle().collect())
+}
+
+fn ring(outer: i32, inner: i32) -> Vec<(i32, i32)> {
+    let hole = disc(inner);
+    disc(outer).into_iter().filter(|pixel| hole.binary_search(pixel).is_err()).collect()
+}
+
+fn stroke(radius: u32, width: u32, alignment: StrokeAlignment) -> Vec<(i32, i32)> {
+    sorted(Annulus::<i32>::stroke(radius, width, alignment).circle().collect())
+}
+
+#[test]
+fn test_stroke_zero_width() {
+    for radius in 0..20_u32 {
+        assert_eq!(stroke(radius, 0, StrokeAlignment::Inside), vec![]);
+        assert_eq!(stroke(radius, 0, StrokeAlignment::Centered), vec![]);
+        assert_eq!(stroke(radius, 0, StrokeAlignment::Outside), vec![]);
+    }
+}
+
+#[test]
+fn test_stroke_alignment() {
+    for radius in 0..30_u32 {
+        for width in 0..12_u32 {
+            let (r, w) = (radius as i32, width as i32);
+            assert_eq!(stroke(radius, width, StrokeAlignment::Inside), ring(r, r - w));
+            assert_eq!(stroke(radius, width, StrokeAlignment::Centered), ring(r + w / 2, r - (w - w / 2)));
+            assert_eq!(stroke(radius, width, StrokeAlignment::Outside), ring(r + w, r));
+        }
+    }
+}
+
+#[test]
+fn test_stroke_wider_than_radius() {
+    assert_eq!(stroke(5, 9, StrokeAlignment::Inside), disc(5));
+    assert_eq!(stroke(3, 9, StrokeAlignment::Centered), disc(7));
+}
+
+#[test]
+fn test_stroke_one_pixel() {
+    for radius in 0..30_u32 {
+        assert_eq!(stroke(radius, 1, StrokeAlignment::Centered), stroke(radius, 1, StrokeAlignment::Inside));
+    }
+}
+
+#[test]
+fn test_stroke_no_gaps_no_overlaps() {
+    for radius in 0..30_u32 {
+        for width in 1..8_u32 {
+            let mut pixels = stroke(radius, width, StrokeAlignment::Inside);
+            pixels.extend(stroke(radius, width, StrokeAlignment::Outside));
+            let len = pixels.len();
+            let pixels = sorted(pixels);
+            let (r, w) = (radius as i32, width as i32);
+            assert_eq!(pixels, ring(r + w, r - w));
+            assert_eq!(pixels.len(), len);
+        }
+    }
+}
+
+#[test]
+fn test_stroke_quadrants() {
+    let iter = Annulus::<i32>::stroke(17_u32, 4_u32, StrokeAlignment::Centered);
+    let ring = ring(19, 15);
+    let part = |keep: fn(&(i32, i32)) -> bool| ring.iter().copied().filter(keep).collect::<Vec<_>>();
+    assert_eq!(sorted(iter.clone().first_quadrant(0).collect()), part(|&(x, y)| x >= 0 && y < 0));
+    assert_eq!(sorted(iter.clone().second_quadrant(0).collect()), part(|&(x, y)| x < 0 && y < 0));
+    assert_eq!(sorted(iter.clone().third_quadrant(0).collect()), part(|&(x, y)| x < 0 && y >= 0));
+    assert_eq!(sorted(iter.clone().fourth_quadrant(0).collect()), part(|&(x, y)| x >= 0 && y >= 0));
+    assert_eq!(sorted(iter.clone().semicircle_top().collect()), part(|&(_, y)| y < 0));
+    assert_eq!(sorted(iter.semicircle_bottom().collect()), part(|&(_, y)| y >= 0));
+}
+
+#[test]
+#[should_panic(expected = "Not possible to convert width to 300")]
+fn test_stroke_width_conversion_panic() {
+    let _ = Annulus::<i8>::stroke(10_u16, 300_u16, StrokeAlignment::Inside);
+}
+
+#[test]
+#[should_panic(expected = "Radius is too large")]
+fn test_stroke_too_large() {
+    let _ = Annulus::<i8>::stroke(40_u8, 1_u8, StrokeAlignment::Outside);
+}
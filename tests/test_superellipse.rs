@@ -0,0 +1,146 @@
+mod common;
+
+use common::sorted;
+use rasterization::{Rasterization, SemicircleFilled, SemiellipseFilled, SuperellipseFilled};
+
+// The half width of the row `y` from the pixel centers inside, with at least one pixel.
+fn reference(rx: i32, ry: i32, exponent: f64, y: i32) -> i32 {
+    let v = ((2 * y + 1) as f64 / (2 * ry) as f64).powf(exponent);
+    (1..=rx)
+        .rev()
+        .find(|&x| ((2 * x - 1) as f64 / (2 * rx) as f64).powf(exponent) + v <= 1.0 + 1e-9)
+        .unwrap_or(1)
+}
+
+#[test]
+fn test_superellipse_empty() {
+    assert_eq!(SuperellipseFilled::<i32>::new(0_u32, 0_u32, 4).count(), 0);
+    assert_eq!(SuperellipseFilled::<i32>::new(0_u32, 5_u32, 4).count(), 0);
+    assert_eq!(SuperellipseFilled::<i32>::new(5_u32, 0_u32, 3).count(), 0);
+    assert_eq!(SuperellipseFilled::<i32>::new(0_u32, 0_u32, 2).count(), 0);
+}
+
+#[test]
+fn test_superellipse_disc() {
+    for radius in 0..60_u32 {
+        let disc = SemicircleFilled::<i32>::new(radius).collect::<Vec<_>>();
+        assert_eq!(SuperellipseFilled::<i32>::new(radius, radius, 2).collect::<Vec<_>>(), disc);
+        assert_eq!(SuperellipseFilled::<i32>::with_exponent(radius, radius, 6, 3).collect::<Vec<_>>(), disc);
+    }
+}
+
+#[test]
+fn test_superellipse_ellipse() {
+    for rx in 1..20_u32 {
+        for ry in 1..20_u32 {
+            if rx != ry {
+                let ellipse = SemiellipseFilled::<i32>::new(rx, ry).collect::<Vec<_>>();
+                assert_eq!(SuperellipseFilled::<i32>::new(rx, ry, 2).collect::<Vec<_>>(), ellipse);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_superellipse_ellipse_not_disc() {
+    // Unequal radii do not delegate to the disc, the exponent 2 still gives the semiellipse.
+    for (rx, ry) in [(5_u32, 6_u32), (60, 59), (300, 41), (7, 200)] {
+        let ellipse = SemiellipseFilled::<i32>::new(rx, ry).collect::<Vec<_>>();
+        assert_eq!(SuperellipseFilled::<i32>::with_exponent(rx, ry, 4, 2).collect::<Vec<_>>(), ellipse);
+        assert_ne!(ellipse, SemicircleFilled::<i32>::new(rx.min(ry)).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_superellipse_matches_reference() {
+    for (p, q) in [(1, 1), (3, 1), (4, 1), (5, 1), (10, 1), (1, 2), (2, 3), (5, 2), (7, 3)] {
+        for (rx, ry) in [(1, 1), (5, 5), (12, 7), (4, 15), (30, 30)] {
+            let rows = SuperellipseFilled::<i32>::with_exponent(rx as u32, ry as u32, p, q).collect::<Vec<_>>();
+            assert_eq!(rows.len(), ry as usize);
+            for (range, y) in rows {
+                let width = reference(rx, ry, p as f64 / q as f64, -y - 1);
+                assert_eq!(range, -width..width, "{p}/{q} {rx} {ry} {y}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_superellipse_exponents() {
+    // A larger exponent gives a boxier shape that contains the smaller ones.
+    let count = |p: u32, q: u32| SuperellipseFilled::<i32>::with_exponent(20_u32, 20_u32, p, q).circle().count();
+    let counts = [(1, 2), (1, 1), (3, 2), (2, 1), (3, 1), (5, 1), (20, 1)].map(|(p, q)| count(p, q));
+    assert!(counts.windows(2).all(|w| w[0] <= w[1]), "{counts:?}");
+    assert!(counts[6] <= 40 * 40);
+}
+
+#[test]
+fn test_superellipse_adapters() {
+    let iter = SuperellipseFilled::<i32>::new(9_u32, 6_u32, 4);
+    let circle = sorted(iter.clone().circle().collect());
+    let mut halves = iter.clone().semicircle_top().collect::<Vec<_>>();
+    halves.extend(iter.clone().semicircle_bottom());
+    assert_eq!(sorted(halves), circle);
+    assert_eq!(circle.len(), 4 * iter.clone().map(|(range, _)| range.end as usize).sum::<usize>());
+    assert!(circle.iter().all(|&(x, y)| circle.binary_search(&(-x - 1, y)).is_ok() && circle.binary_search(&(x, -y - 1)).is_ok()));
+}
+
+#[test]
+fn test_superellipse_size_hint() {
+    let mut iter = SuperellipseFilled::<i32>::new(9_u32, 6_u32, 5);
+    loop {
+        let count = iter.clone().count();
+        assert_eq!(iter.size_hint(), (count, Some(count)));
+        if iter.next().is_none() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn test_superellipse_large_exponent() {
+    // Powers that overflow the exact comparison fall back to the rational one.
+    let rows = SuperellipseFilled::<i64>::new(1000_u32, 1000_u32, 40).collect::<Vec<_>>();
+    assert_eq!(rows.len(), 1000);
+    assert_eq!(rows[0], (-1000..1000, -1));
+}
+
+#[test]
+#[should_panic(expected = "The exponent must be positive")]
+fn test_superellipse_zero_exponent() {
+    let _ = SuperellipseFilled::<i32>::with_exponent(5_u32, 5_u32, 3, 0);
+}
+
+#[test]
+fn test_superellipse_disc_is_not_pixel_centers() {
+    // The disc rows come from the error term of SemicircleFilled rather than the pixel centers,
+    // so the exponent 2 cannot go through the general rows, while exponents next to it do.
+    let radius = 5;
+    let disc = SemicircleFilled::<i32>::new(radius as u32).collect::<Vec<_>>();
+    let centers = (0..radius).map(|y| reference(radius, radius, 2.0, y)).collect::<Vec<_>>();
+    assert_ne!(disc.iter().map(|(range, _)| range.end).collect::<Vec<_>>(), centers);
+    for (p, q) in [(1999, 1000), (2001, 1000)] {
+        let rows = SuperellipseFilled::<i32>::with_exponent(radius as u32, radius as u32, p, q);
+        assert_eq!(rows.map(|(range, _)| range.end).collect::<Vec<_>>(), centers, "{p}/{q}");
+    }
+}
+
+#[test]
+fn test_superellipse_powf_accuracy() {
+    // The pixel at the end of each row lies inside and the next one outside, within 1e-10.
+    let sum = |x: i32, y: i32, r: i32, n: f64| {
+        ((2 * x - 1) as f64 / (2 * r) as f64).powf(n) + ((2 * y + 1) as f64 / (2 * r) as f64).powf(n)
+    };
+    let exponents = [(999, 1000), (1001, 1000), (1999, 1000), (2001, 1000), (40, 1), (201, 2), (1000, 1)];
+    for (p, q) in exponents {
+        let n = p as f64 / q as f64;
+        for radius in [7, 100, 1000] {
+            let rows = SuperellipseFilled::<i64>::with_exponent(radius as u32, radius as u32, p, q);
+            for (range, y) in rows {
+                let (width, y) = (range.end as i32, -y as i32 - 1);
+                assert!(width == 1 || sum(width, y, radius, n) <= 1.0 + 1e-10, "{p}/{q} {radius} {y}");
+                assert!(width == radius || sum(width + 1, y, radius, n) > 1.0 - 1e-10, "{p}/{q} {radius} {y}");
+            }
+        }
+    }
+}
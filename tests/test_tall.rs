@@ -0,0 +1,94 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{Rasterization, RoundedRect, SemicircleCentered, SemicircleFilled};
+
+#[test]
+fn test_tall_empty() {
+    let vec = SemicircleFilled::<i32>::new(0_u32).circle_tall(-5, 5).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+    let vec = SemicircleFilled::<i32>::new(0_u32).circle_tall_spans(-5, 5).collect::<Vec<_>>();
+    assert_eq!(vec, vec![]);
+}
+
+#[test]
+fn test_tall_without_stretch() {
+    for radius in 0..30_u32 {
+        let iter = SemicircleFilled::<i32>::new(radius);
+        assert_eq!(sorted(iter.clone().circle_tall(0, 0).collect()), sorted(iter.clone().circle().collect()));
+        let iter = SemicircleCentered::<i32>::new(radius);
+        assert_eq!(sorted(iter.clone().circle_tall(0, 0).collect()), sorted(iter.circle().collect()));
+    }
+}
+
+#[test]
+fn test_tall_transposed_long() {
+    for radius in 0..30_u32 {
+        for long in 0..8 {
+            let iter = SemicircleFilled::<i32>::new(radius);
+            let tall = sorted(iter.clone().circle_tall(-long, 0).map(|(x, y)| (y, x)).collect());
+            assert_eq!(tall, sorted(iter.clone().circle_long(-long, 0).collect()));
+            let tall = sorted(iter.clone().circle_tall(0, long).map(|(x, y)| (y, x)).collect());
+            assert_eq!(tall, sorted(iter.circle_long(0, long).collect()));
+        }
+    }
+}
+
+#[test]
+fn test_tall_rounded_rect() {
+    for radius in 0..30_u32 {
+        for long in 0..8_u32 {
+            let (r, l) = (radius as i32, long as i32);
+            let tall = sorted(SemicircleFilled::<i32>::new(radius).circle_tall(-l, 0).collect());
+            let rect = RoundedRect::<i32>::new(2 * radius, 2 * radius + long, radius).pixels().offset(-r, -r - l);
+            assert_eq!(tall, sorted(rect.collect()));
+        }
+    }
+}
+
+#[test]
+fn test_tall_halves() {
+    for radius in 0..30_u32 {
+        for (start, end) in [(0, 0), (-3, 0), (0, 4), (-2, 5)] {
+            let iter = SemicircleFilled::<i32>::new(radius);
+            let tall = iter.clone().circle_tall(start, end).collect::<Vec<_>>();
+            let mut halves = iter.clone().semicircle_left_tall(start, end).collect::<Vec<_>>();
+            assert!(halves.iter().all(|&(x, _)| x < 0));
+            halves.extend(iter.clone().semicircle_right_tall(start, end));
+            assert_eq!(halves.len(), tall.len());
+            assert_eq!(sorted(halves), sorted(tall.clone()));
+
+            let mut unique = sorted(tall);
+            let len = unique.len();
+            unique.dedup();
+            assert_eq!(unique.len(), len);
+        }
+    }
+}
+
+#[test]
+fn test_tall_spans() {
+    for radius in 0..20_u32 {
+        let iter = SemicircleFilled::<i32>::new(radius);
+        assert_eq!(pixels(iter.clone().circle_tall_spans(-4, 2)), sorted(iter.clone().circle_tall(-4, 2).collect()));
+        assert_eq!(pixels(iter.clone().semicircle_left_tall_spans(-4, 2)), sorted(iter.clone().semicircle_left_tall(-4, 2).collect()));
+        assert_eq!(pixels(iter.clone().semicircle_right_tall_spans(-4, 2)), sorted(iter.semicircle_right_tall(-4, 2).collect()));
+    }
+}
+
+#[test]
+fn test_tall_centered() {
+    let iter = SemicircleCentered::<i32>::new(6_u32);
+    let tall = sorted(iter.clone().circle_tall(-3, 0).collect());
+    let circle = sorted(iter.circle().collect());
+    assert_eq!(tall.len(), circle.len() + 3 * 13);
+    assert!(tall.iter().all(|&(_, y)| (-9..=6).contains(&y)));
+}
+
+#[test]
+fn test_tall_size_hint() {
+    let iter = SemicircleFilled::<i32>::new(15_u32).circle_tall(-7, 3);
+    let (lower, upper) = iter.size_hint();
+    let len = iter.count();
+    assert!(lower <= len && upper.map_or(true, |upper| upper >= len));
+}
@@ -0,0 +1,201 @@
+mod common;
+
+use common::{pixels, sorted};
+use rasterization::{LineCap, Rasterization, SemicircleFilled, ThickLine};
+
+fn disc(center: (i32, i32), radius: u32) -> Vec<(i32, i32)> {
+    SemicircleFilled::<i32>::new(radius).circle().offset(center.0, center.1).collect()
+}
+
+// The round cap, the disc for an even width and the pixels with centers within half of it otherwise.
+fn cap(center: (i32, i32), width: i32) -> Vec<(i32, i32)> {
+    if width % 2 == 0 {
+        return disc(center, width as u32 / 2);
+    }
+    let mut vec = Vec::new();
+    for y in center.1 - width..center.1 + width {
+        for x in center.0 - width..center.0 + width {
+            if (2 * (x - center.0) + 1).pow(2) + (2 * (y - center.1) + 1).pow(2) < width * width {
+                vec.push((x, y));
+            }
+        }
+    }
+    vec
+}
+
+// Whether `value` lies within `reach2.sqrt()` below or above zero, taking the upper edge only.
+fn within(value: i128, reach2: i128) -> bool {
+    value * value < reach2 || (value * value == reach2 && value > 0)
+}
+
+// The pixels whose doubled centers lie within the width from the segment.
+fn reference(start: (i32, i32), end: (i32, i32), width: i32, square: bool) -> Vec<(i32, i32)> {
+    let d = match (end.0 - start.0, end.1 - start.1) {
+        (0, 0) => (1, 0),
+        d => d,
+    };
+    let (dx, dy) = (d.0 as i128, d.1 as i128);
+    let length = dx * (end.0 - start.0) as i128 + dy * (end.1 - start.1) as i128;
+    let reach2 = (width as i128).pow(2) * (dx * dx + dy * dy);
+    let mut vec = Vec::new();
+    for y in start.1.min(end.1) - width - 1..start.1.max(end.1) + width + 1 {
+        for x in start.0.min(end.0) - width - 1..start.0.max(end.0) + width + 1 {
+            let u = (2 * x + 1 - 2 * start.0) as i128;
+            let v = (2 * y + 1 - 2 * start.1) as i128;
+            let cross = dx * v - dy * u;
+            let dot = dx * u + dy * v;
+            let along = if square {
+                (dot >= 0 || within(dot, reach2)) && (dot <= 2 * length || within(dot - 2 * length, reach2))
+            } else {
+                (0..=2 * length).contains(&dot)
+            };
+            if within(cross, reach2) && along {
+                vec.push((x, y));
+            }
+        }
+    }
+    sorted(vec)
+}
+
+fn endpoints() -> Vec<((i32, i32), (i32, i32))> {
+    let mut vec = Vec::new();
+    for x in -7..=7 {
+        for y in -7..=7 {
+            vec.push(((0, 0), (x, y)));
+            vec.push(((3, -2), (x * 3, y)));
+        }
+    }
+    vec
+}
+
+#[test]
+fn test_thick_line_butt() {
+    for (start, end) in endpoints() {
+        for width in 0..9_u32 {
+            let line = pixels(ThickLine::<i32>::new(start, end, width, LineCap::Butt));
+            assert_eq!(line, reference(start, end, width as i32, false), "{start:?} {end:?} {width}");
+        }
+    }
+}
+
+#[test]
+fn test_thick_line_square() {
+    for (start, end) in endpoints() {
+        for width in 0..9_u32 {
+            let line = pixels(ThickLine::<i32>::new(start, end, width, LineCap::Square));
+            assert_eq!(line, reference(start, end, width as i32, true), "{start:?} {end:?} {width}");
+        }
+    }
+}
+
+#[test]
+fn test_thick_line_round() {
+    for (start, end) in endpoints() {
+        for width in 0..9_u32 {
+            let line = pixels(ThickLine::<i32>::new(start, end, width, LineCap::Round));
+            let mut expected = reference(start, end, width as i32, false);
+            expected.extend(cap(start, width as i32));
+            expected.extend(cap(end, width as i32));
+            let mut expected = sorted(expected);
+            expected.dedup();
+            assert_eq!(line, expected, "{start:?} {end:?} {width}");
+        }
+    }
+}
+
+#[test]
+fn test_thick_line_no_duplicates() {
+    for (start, end) in endpoints() {
+        for cap in [LineCap::Butt, LineCap::Square, LineCap::Round] {
+            let rows = ThickLine::<i32>::new(start, end, 5_u32, cap).collect::<Vec<_>>();
+            assert!(rows.iter().all(|(range, _)| !range.is_empty()));
+            assert!(rows.windows(2).all(|w| w[0].1 < w[1].1 || (w[0].1 == w[1].1 && w[0].0.end < w[1].0.start)));
+        }
+    }
+}
+
+#[test]
+fn test_thick_line_round_stadium() {
+    for radius in 1..20_u32 {
+        for long in 0..10 {
+            let iter = SemicircleFilled::<i32>::new(radius);
+            let line = pixels(ThickLine::<i32>::new((0, 0), (long, 0), 2 * radius, LineCap::Round));
+            assert_eq!(line, sorted(iter.clone().circle_long(0, long).collect()));
+            let line = pixels(ThickLine::<i32>::new((0, -long), (0, 0), 2 * radius, LineCap::Round));
+            assert_eq!(line, sorted(iter.circle_tall(-long, 0).collect()));
+        }
+    }
+}
+
+#[test]
+fn test_thick_line_odd_width() {
+    for width in [1_u32, 3, 5, 7] {
+        let w = width as i32;
+        let rows = ThickLine::<i32>::new((0, 0), (6, 0), width, LineCap::Butt).collect::<Vec<_>>();
+        assert_eq!(rows, (-w / 2..w / 2 + 1).map(|y| (0..6, y)).collect::<Vec<_>>());
+        let rows = ThickLine::<i32>::new((0, 0), (0, 6), width, LineCap::Butt).collect::<Vec<_>>();
+        assert_eq!(rows, (0..6).map(|y| (-w / 2 - 1..w / 2, y)).collect::<Vec<_>>());
+        let square = pixels(ThickLine::<i32>::new((2, 2), (2, 2), width, LineCap::Square));
+        assert_eq!(square.len(), (width * width) as usize);
+        let round = pixels(ThickLine::<i32>::new((2, 2), (2, 2), width, LineCap::Round));
+        assert_eq!(round, sorted(cap((2, 2), w)));
+        assert_eq!(round, sorted(round.iter().map(|&(x, y)| (3 - x, 3 - y)).collect()));
+    }
+}
+
+#[test]
+fn test_thick_line_zero_length() {
+    assert_eq!(ThickLine::<i32>::new((2, 2), (2, 2), 6_u32, LineCap::Butt).count(), 0);
+    let square = pixels(ThickLine::<i32>::new((2, 2), (2, 2), 6_u32, LineCap::Square));
+    assert_eq!(square, sorted((-1..5).flat_map(|x| (-1..5).map(move |y| (x, y))).collect()));
+    let round = pixels(ThickLine::<i32>::new((2, 2), (2, 2), 6_u32, LineCap::Round));
+    assert_eq!(round, sorted(disc((2, 2), 3)));
+}
+
+#[test]
+fn test_thick_line_pixels() {
+    let iter = ThickLine::<i32>::new((-5, 3), (9, -4), 4_u32, LineCap::Round);
+    assert_eq!(sorted(iter.clone().pixels().collect()), pixels(iter));
+}
+
+#[test]
+fn test_thick_line_size_hint() {
+    let mut iter = ThickLine::<i32>::new((0, 0), (10, 7), 7_u32, LineCap::Round);
+    loop {
+        let (lower, upper) = iter.size_hint();
+        let count = iter.clone().count();
+        assert!(lower <= count && count <= upper.unwrap());
+        if iter.next().is_none() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn test_thick_line_types() {
+    let wide = ThickLine::<i64>::new((-20, 10), (25, -15), 9_u8, LineCap::Round).collect::<Vec<_>>();
+    let narrow = ThickLine::<i8>::new((-20, 10), (25, -15), 9_u8, LineCap::Round)
+        .map(|(range, y)| (range.start as i64..range.end as i64, y as i64))
+        .collect::<Vec<_>>();
+    assert_eq!(wide, narrow);
+}
+
+#[test]
+#[should_panic(expected = "The line does not fit into the coordinate type")]
+fn test_thick_line_does_not_fit() {
+    let _ = ThickLine::<i8>::new((0, 0), (120, 0), 10_u8, LineCap::Butt);
+}
+
+#[test]
+#[should_panic(expected = "The line is too wide for its length")]
+fn test_thick_line_too_wide() {
+    let limit = 1_i128 << 60;
+    let _ = ThickLine::<i128>::new((-limit, -limit), (limit, limit), 1_u128 << 60, LineCap::Butt);
+}
+
+#[test]
+fn test_thick_line_large_coordinates() {
+    let limit = 1_i128 << 60;
+    let rows = ThickLine::<i128>::new((-limit, 0), (limit, 0), 4_u8, LineCap::Square).collect::<Vec<_>>();
+    assert_eq!(rows, (-2..2).map(|y| (-limit - 2..limit + 2, y)).collect::<Vec<_>>());
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::sorted;
+use rasterization::{FillRule, PolygonFilled, Rasterization, Triangle};
+
+fn pixels(vertices: [(i32, i32); 3]) -> Vec<(i32, i32)> {
+    sorted(Triangle::<i32>::new(vertices).map(|(x, y, _)| (x, y)).collect())
+}
+
+const TRIANGLES: [[(i32, i32); 3]; 6] = [
+    [(0, 0), (17, 3), (5, 14)],
+    [(5, 14), (17, 3), (0, 0)],
+    [(-6, -2), (9, -7), (3, 11)],
+    [(0, 0), (20, 1), (1, 2)],
+    [(2, -9), (3, 9), (-1, 0)],
+    [(0, 0), (6, 0), (0, 6)],
+];
+
+#[test]
+fn test_triangle_empty() {
+    assert_eq!(Triangle::<i32>::new([(0, 0), (0, 0), (0, 0)]).count(), 0);
+    assert_eq!(Triangle::<i32>::new([(0, 0), (3, 3), (6, 6)]).count(), 0);
+    assert_eq!(Triangle::<i32>::new([(0, 2), (9, 2), (4, 2)]).count(), 0);
+}
+
+#[test]
+fn test_triangle_matches_polygon() {
+    for vertices in TRIANGLES {
+        let polygon = PolygonFilled::<i32>::new(&vertices, FillRule::NonZero).pixels().collect();
+        assert_eq!(pixels(vertices), sorted(polygon), "{vertices:?}");
+    }
+}
+
+#[test]
+fn test_triangle_orientation() {
+    for [a, b, c] in TRIANGLES {
+        assert_eq!(pixels([a, b, c]), pixels([c, b, a]));
+        assert_eq!(pixels([a, b, c]), pixels([b, c, a]));
+    }
+}
+
+#[test]
+fn test_triangle_shared_edges() {
+    // A fan of triangles around a center tiles the octagon without overlaps or gaps.
+    let center = (7, 5);
+    let ring = [(0, 0), (7, -3), (15, 0), (17, 6), (14, 13), (6, 15), (-1, 12), (-4, 5)];
+    let mut tiles = Vec::new();
+    for i in 0..ring.len() {
+        tiles.extend(pixels([center, ring[i], ring[(i + 1) % ring.len()]]));
+    }
+    let octagon = PolygonFilled::<i32>::new(&ring, FillRule::NonZero).pixels().collect();
+    assert_eq!(sorted(tiles), sorted(octagon));
+}
+
+#[test]
+fn test_triangle_weights() {
+    for vertices in TRIANGLES {
+        for (x, y, weights) in Triangle::<i32>::new(vertices) {
+            assert!((weights.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+            assert!(weights.iter().all(|&w| (0.0..=1.0).contains(&w)));
+            // The weighted vertices give the pixel center.
+            let px = (0..3).map(|i| weights[i] * vertices[i].0 as f32).sum::<f32>();
+            let py = (0..3).map(|i| weights[i] * vertices[i].1 as f32).sum::<f32>();
+            assert!((px - (x as f32 + 0.5)).abs() < 1e-3 && (py - (y as f32 + 0.5)).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn test_triangle_weights_order() {
+    let [a, b, c] = TRIANGLES[0];
+    let forward = Triangle::<i32>::new([a, b, c]).collect::<Vec<_>>();
+    let backward = Triangle::<i32>::new([c, b, a]).collect::<Vec<_>>();
+    assert_eq!(forward.len(), backward.len());
+    for ((x, y, w), (bx, by, bw)) in forward.into_iter().zip(backward) {
+        assert_eq!((x, y), (bx, by));
+        assert_eq!(w, [bw[2], bw[1], bw[0]]);
+    }
+}
+
+#[test]
+fn test_triangle_vertex_colors() {
+    let colors = [[255, 0, 0], [0, 255, 0], [0, 0, 255]];
+    let vec = Triangle::<i32>::new([(0, 0), (40, 0), (0, 40)]).vertex_colors(colors).collect::<Vec<_>>();
+    assert_eq!(vec.len(), Triangle::<i32>::new([(0, 0), (40, 0), (0, 40)]).count());
+    assert!(vec.iter().all(|(_, _, [r, g, b])| (254..=256).contains(&(*r as u32 + *g as u32 + *b as u32))));
+    let same = Triangle::<i32>::new(TRIANGLES[2]).vertex_colors([[9, 80, 200]; 3]);
+    assert!(same.clone().count() > 0);
+    assert!(same.into_iter().all(|(_, _, color)| color == [9, 80, 200]));
+}
+
+#[test]
+fn test_triangle_size_hint() {
+    let mut iter = Triangle::<i32>::new(TRIANGLES[0]);
+    loop {
+        assert!(iter.size_hint().0 <= iter.clone().count());
+        if iter.next().is_none() {
+            break;
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "The triangle is too large")]
+fn test_triangle_too_large() {
+    let _ = Triangle::<i64>::new([(0, 0), (i64::MAX, 0), (0, 1)]);
+}